@@ -28,14 +28,26 @@ pub fn get_cache_path() -> PathBuf {
 
 pub fn load_config() -> AppConfig {
     let path = get_config_path();
-    if path.exists() {
+    let config = if path.exists() {
         fs::read_to_string(&path)
             .ok()
             .and_then(|s| serde_json::from_str(&s).ok())
             .unwrap_or_default()
     } else {
         AppConfig::default()
+    };
+    migrate_config(config)
+}
+
+/// Bring configs written by older versions up to date
+pub fn migrate_config(mut config: AppConfig) -> AppConfig {
+    // Single scan_root predates the scan_roots list
+    if config.scan_roots.is_empty() {
+        if let Some(root) = &config.scan_root {
+            config.scan_roots.push(root.clone());
+        }
     }
+    config
 }
 
 pub fn save_config(config: &AppConfig) -> Result<(), String> {
@@ -114,6 +126,32 @@ mod tests {
         assert!(config.scan_root.is_none());
         assert!(config.recent_projects.is_empty());
         assert!(config.preferred_ide.is_none());
+        assert!(config.recent_searches.is_empty());
+        assert!(config.scan_roots.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_config_single_scan_root() {
+        let old: AppConfig = serde_json::from_str(
+            r#"{"favorites": [], "hidden": [], "scan_root": "/home/me/work", "recent_projects": [], "preferred_ide": null}"#,
+        )
+        .unwrap();
+        let config = migrate_config(old);
+        assert_eq!(config.scan_roots, vec!["/home/me/work"]);
+        assert_eq!(config.scan_root.as_deref(), Some("/home/me/work"));
+    }
+
+    #[test]
+    fn test_migrate_config_keeps_existing_scan_roots() {
+        let config = migrate_config(AppConfig {
+            scan_root: Some("/a".to_string()),
+            scan_roots: vec!["/a".to_string(), "/b".to_string()],
+            ..Default::default()
+        });
+        assert_eq!(config.scan_roots, vec!["/a", "/b"]);
+
+        let config = migrate_config(AppConfig::default());
+        assert!(config.scan_roots.is_empty());
     }
 
     #[test]
@@ -0,0 +1,221 @@
+//! Structured command errors
+//!
+//! Commands report failures as an `AppError` so the frontend can tell a missing
+//! tool apart from a parse failure or an IO error. Serialized errors carry a
+//! `kind` tag alongside a human-readable `message`.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AppError {
+    /// A cargo subcommand the operation needs isn't installed
+    ToolNotInstalled {
+        tool: String,
+        install_cmd: String,
+        message: String,
+    },
+    /// A private registry rejected the request for lack of credentials
+    RegistryAuth { registry: String, message: String },
+    /// The tool ran but reported a failure
+    CommandFailed { message: String },
+    /// The tool's output couldn't be parsed
+    Parse { message: String },
+    /// Filesystem or process spawn failure
+    Io { message: String },
+    /// An expected file or report doesn't exist
+    NotFound { message: String },
+    /// The background task panicked or was cancelled
+    Internal { message: String },
+}
+
+impl AppError {
+    pub fn tool_not_installed(tool: &str, install_cmd: &str) -> Self {
+        AppError::ToolNotInstalled {
+            tool: tool.to_string(),
+            install_cmd: install_cmd.to_string(),
+            message: format!("{} is not installed. Install with: {}", tool, install_cmd),
+        }
+    }
+
+    pub fn registry_auth(registry: &str) -> Self {
+        AppError::RegistryAuth {
+            registry: registry.to_string(),
+            message: format!(
+                "Private registry auth required for `{}`. Run `cargo login --registry {}` or set CARGO_REGISTRIES_{}_TOKEN.",
+                registry,
+                registry,
+                registry.to_uppercase().replace('-', "_")
+            ),
+        }
+    }
+
+    pub fn command_failed(message: impl Into<String>) -> Self {
+        AppError::CommandFailed {
+            message: message.into(),
+        }
+    }
+
+    pub fn parse(message: impl Into<String>) -> Self {
+        AppError::Parse {
+            message: message.into(),
+        }
+    }
+
+    pub fn io(message: impl Into<String>) -> Self {
+        AppError::Io {
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        AppError::NotFound {
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        AppError::Internal {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::ToolNotInstalled { message, .. }
+            | AppError::RegistryAuth { message, .. }
+            | AppError::CommandFailed { message }
+            | AppError::Parse { message }
+            | AppError::Io { message }
+            | AppError::NotFound { message }
+            | AppError::Internal { message } => message,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl From<std::io::Error> for AppError {
+    fn from(e: std::io::Error) -> Self {
+        AppError::io(e.to_string())
+    }
+}
+
+impl From<AppError> for String {
+    fn from(e: AppError) -> Self {
+        e.message().to_string()
+    }
+}
+
+/// Deserialize an optional error field, accepting the plain strings written by older
+/// versions (e.g. in cache.json) as `CommandFailed`
+pub fn deserialize_optional<'de, D>(deserializer: D) -> Result<Option<AppError>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StoredError {
+        Structured(AppError),
+        Legacy(String),
+    }
+
+    Ok(
+        Option::<StoredError>::deserialize(deserializer)?.map(|stored| match stored {
+            StoredError::Structured(e) => e,
+            StoredError::Legacy(message) => AppError::command_failed(message),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn kind_of(error: &AppError) -> String {
+        serde_json::to_value(error).unwrap()["kind"]
+            .as_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_error_kinds_serialize() {
+        let cases = [
+            (
+                AppError::tool_not_installed("cargo-audit", "cargo install cargo-audit"),
+                "tool_not_installed",
+            ),
+            (AppError::registry_auth("my-corp"), "registry_auth"),
+            (AppError::command_failed("exit 101"), "command_failed"),
+            (AppError::parse("bad json"), "parse"),
+            (AppError::io("permission denied"), "io"),
+            (AppError::not_found("no report"), "not_found"),
+            (AppError::internal("task panicked"), "internal"),
+        ];
+        for (error, kind) in cases {
+            assert_eq!(kind_of(&error), kind);
+        }
+    }
+
+    #[test]
+    fn test_tool_not_installed_serializes_install_hint() {
+        let error = AppError::tool_not_installed("cargo-bloat", "cargo install cargo-bloat");
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            json!({
+                "kind": "tool_not_installed",
+                "tool": "cargo-bloat",
+                "install_cmd": "cargo install cargo-bloat",
+                "message": "cargo-bloat is not installed. Install with: cargo install cargo-bloat"
+            })
+        );
+    }
+
+    #[test]
+    fn test_registry_auth_names_token_variable() {
+        let error = AppError::registry_auth("my-corp");
+        assert!(error.message().contains("cargo login --registry my-corp"));
+        assert!(error.message().contains("CARGO_REGISTRIES_MY_CORP_TOKEN"));
+    }
+
+    #[test]
+    fn test_error_message_and_conversions() {
+        let error = AppError::parse("unexpected token");
+        assert_eq!(error.message(), "unexpected token");
+        assert_eq!(error.to_string(), "unexpected token");
+        assert_eq!(String::from(error), "unexpected token");
+
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        assert_eq!(AppError::from(io_error), AppError::io("missing"));
+    }
+
+    #[derive(Deserialize)]
+    struct Stored {
+        #[serde(default, deserialize_with = "deserialize_optional")]
+        error: Option<AppError>,
+    }
+
+    #[test]
+    fn test_deserialize_optional_accepts_legacy_strings() {
+        let stored: Stored = serde_json::from_str(r#"{"error": "old failure"}"#).unwrap();
+        assert_eq!(stored.error, Some(AppError::command_failed("old failure")));
+
+        let stored: Stored =
+            serde_json::from_str(r#"{"error": {"kind": "parse", "message": "bad"}}"#).unwrap();
+        assert_eq!(stored.error, Some(AppError::parse("bad")));
+
+        let stored: Stored = serde_json::from_str(r#"{"error": null}"#).unwrap();
+        assert!(stored.error.is_none());
+        let stored: Stored = serde_json::from_str("{}").unwrap();
+        assert!(stored.error.is_none());
+    }
+}
@@ -0,0 +1,98 @@
+//! Bounded parallel execution for batch analyses
+//!
+//! Batch commands run one cargo subprocess per project. `run_bounded` fans
+//! the work out over a fixed number of worker threads so a large project
+//! list can't spawn hundreds of processes at once.
+
+use std::sync::Mutex;
+use std::thread;
+
+/// Default job limit: one per available CPU
+pub fn default_parallel_jobs() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Apply `job` to every item with at most `max_jobs` running at once (0 = unlimited).
+/// Results come back in the same order as `items`.
+pub fn run_bounded<T, R, F>(items: Vec<T>, max_jobs: usize, job: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let total = items.len();
+    let workers = if max_jobs == 0 {
+        total
+    } else {
+        max_jobs.min(total)
+    };
+    if workers <= 1 {
+        return items.into_iter().map(job).collect();
+    }
+
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new(Vec::with_capacity(total));
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                // Release the queue before running the job
+                let next = queue.lock().unwrap().next();
+                let Some((index, item)) = next else {
+                    break;
+                };
+                let result = job(item);
+                results.lock().unwrap().push((index, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Run 12 sleeping jobs and return the results and the peak concurrency
+    fn run_tracked(max_jobs: usize) -> (Vec<usize>, usize) {
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let results = run_bounded((0..12).collect(), max_jobs, |n: usize| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(10));
+            running.fetch_sub(1, Ordering::SeqCst);
+            n * 2
+        });
+        (results, peak.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    fn test_run_bounded_never_exceeds_limit() {
+        for max_jobs in [1, 2, 3] {
+            let (results, peak) = run_tracked(max_jobs);
+            assert!(peak <= max_jobs, "peak {} > limit {}", peak, max_jobs);
+            assert_eq!(results, (0..12).map(|n| n * 2).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_run_bounded_unlimited() {
+        let (results, peak) = run_tracked(0);
+        assert!(peak <= 12);
+        assert_eq!(results.len(), 12);
+        assert_eq!(results[11], 22);
+    }
+
+    #[test]
+    fn test_run_bounded_empty() {
+        let results: Vec<u8> = run_bounded(Vec::<u8>::new(), 4, |n| n);
+        assert!(results.is_empty());
+    }
+}
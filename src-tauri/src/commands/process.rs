@@ -0,0 +1,288 @@
+//! Child process tracking
+//!
+//! Spawned cargo/tool processes are wrapped in a `ChildGuard` so they are
+//! terminated and reaped if the runner bails out early, and so any still
+//! running when the app exits can be shut down in one place.
+//!
+//! Each child leads its own process group, so signals also reach the rustc and
+//! build-script processes cargo starts. Runners live in `spawn_blocking` tasks,
+//! which can't be aborted, so dropping the guard is the only early-exit path.
+
+use std::collections::HashSet;
+use std::io;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::sync::{Mutex, OnceLock};
+#[cfg(unix)]
+use std::thread;
+use std::time::Duration;
+#[cfg(unix)]
+use std::time::Instant;
+
+const DEFAULT_GRACE_PERIOD: Duration = Duration::from_secs(2);
+#[cfg(unix)]
+const POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+// ============ Process Registry ============
+
+fn live_pids() -> &'static Mutex<HashSet<u32>> {
+    static PIDS: OnceLock<Mutex<HashSet<u32>>> = OnceLock::new();
+    PIDS.get_or_init(Default::default)
+}
+
+fn register(pid: u32) {
+    if let Ok(mut pids) = live_pids().lock() {
+        pids.insert(pid);
+    }
+}
+
+fn unregister(pid: u32) {
+    if let Ok(mut pids) = live_pids().lock() {
+        pids.remove(&pid);
+    }
+}
+
+/// Signal the process group led by `pid`
+#[cfg(unix)]
+fn signal_group(pid: u32, signal: &str) {
+    let _ = Command::new("kill")
+        .args([signal, "--", &format!("-{}", pid)])
+        .output();
+}
+
+/// Whether `pid` has exited. An exited child stays a zombie until it is reaped,
+/// so its pid and process group id can't be reused before then.
+#[cfg(unix)]
+fn has_exited(pid: u32) -> bool {
+    Command::new("ps")
+        .args(["-o", "stat=", "-p", &pid.to_string()])
+        .output()
+        .map(|o| {
+            let state = String::from_utf8_lossy(&o.stdout);
+            let state = state.trim();
+            state.is_empty() || state.starts_with('Z')
+        })
+        .unwrap_or(true)
+}
+
+/// Terminate every tracked child that is still running (used on app exit)
+pub fn terminate_all_children() {
+    let pids: Vec<u32> = live_pids()
+        .lock()
+        .map(|mut pids| pids.drain().collect())
+        .unwrap_or_default();
+    if pids.is_empty() {
+        return;
+    }
+
+    #[cfg(unix)]
+    {
+        for pid in &pids {
+            signal_group(*pid, "-TERM");
+        }
+        thread::sleep(DEFAULT_GRACE_PERIOD);
+        for pid in &pids {
+            signal_group(*pid, "-KILL");
+        }
+    }
+
+    #[cfg(windows)]
+    for pid in &pids {
+        let _ = Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &pid.to_string()])
+            .output();
+    }
+}
+
+// ============ Child Guard ============
+
+/// Owns a spawned child and, when dropped while the child is still running,
+/// sends SIGTERM to its process group, escalates to SIGKILL after a grace
+/// period, and reaps it.
+pub struct ChildGuard {
+    child: Option<Child>,
+    pid: u32,
+    grace_period: Duration,
+}
+
+impl ChildGuard {
+    pub fn spawn(command: &mut Command) -> io::Result<Self> {
+        #[cfg(unix)]
+        command.process_group(0);
+        let child = command.spawn()?;
+        let pid = child.id();
+        register(pid);
+        Ok(ChildGuard {
+            child: Some(child),
+            pid,
+            grace_period: DEFAULT_GRACE_PERIOD,
+        })
+    }
+
+    /// Run `command` to completion under a guard, capturing output like `Command::output`
+    pub fn output(command: &mut Command) -> io::Result<Output> {
+        Self::spawn(
+            command
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped()),
+        )?
+        .wait_with_output()
+    }
+
+    pub fn child_mut(&mut self) -> &mut Child {
+        self.child.as_mut().expect("child already reaped")
+    }
+
+    pub fn wait(&mut self) -> io::Result<ExitStatus> {
+        let status = self.child_mut().wait();
+        if status.is_ok() {
+            unregister(self.pid);
+        }
+        status
+    }
+
+    pub fn wait_with_output(mut self) -> io::Result<Output> {
+        let child = self.child.take().expect("child already reaped");
+        let output = child.wait_with_output();
+        unregister(self.pid);
+        output
+    }
+
+    /// Signal the child's group, then reap it. Only called while the child is
+    /// un-reaped, and it is only reaped after the last signal, so its pid still
+    /// names this group when the signals are sent.
+    fn terminate(child: &mut Child, grace_period: Duration) {
+        #[cfg(unix)]
+        {
+            let pid = child.id();
+            signal_group(pid, "-TERM");
+            let deadline = Instant::now() + grace_period;
+            while Instant::now() < deadline && !has_exited(pid) {
+                thread::sleep(POLL_INTERVAL);
+            }
+            // rustc and build scripts can outlive cargo, so the group is killed either way
+            signal_group(pid, "-KILL");
+        }
+        #[cfg(not(unix))]
+        let _ = grace_period;
+
+        // Child::kill sends SIGKILL on unix; wait() reaps so no zombie is left behind
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        if let Some(mut child) = self.child.take() {
+            if matches!(child.try_wait(), Ok(None)) {
+                Self::terminate(&mut child, self.grace_period);
+            }
+        }
+        unregister(self.pid);
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn is_running(pid: u32) -> bool {
+        Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_child_guard_kills_on_drop() {
+        let guard = ChildGuard::spawn(Command::new("sleep").arg("30")).unwrap();
+        let pid = guard.pid;
+        assert!(is_running(pid));
+        assert!(live_pids().lock().unwrap().contains(&pid));
+
+        drop(guard);
+
+        assert!(!is_running(pid));
+        assert!(!live_pids().lock().unwrap().contains(&pid));
+    }
+
+    #[test]
+    fn test_child_guard_escalates_to_sigkill() {
+        // Ignores SIGTERM, so only the SIGKILL fallback can stop it
+        let mut guard = ChildGuard::spawn(
+            Command::new("sh")
+                .arg("-c")
+                .arg("trap '' TERM; while :; do sleep 1; done"),
+        )
+        .unwrap();
+        guard.grace_period = Duration::from_millis(200);
+        let pid = guard.pid;
+        thread::sleep(Duration::from_millis(100));
+
+        drop(guard);
+
+        assert!(!is_running(pid));
+    }
+
+    #[test]
+    fn test_child_guard_kills_grandchildren() {
+        use std::io::{BufRead, BufReader};
+
+        let mut guard = ChildGuard::spawn(
+            Command::new("sh")
+                .arg("-c")
+                .arg("sleep 30 & echo $!; wait")
+                .stdout(std::process::Stdio::piped()),
+        )
+        .unwrap();
+        let mut line = String::new();
+        BufReader::new(guard.child_mut().stdout.as_mut().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let grandchild: u32 = line.trim().parse().unwrap();
+        assert!(is_running(grandchild));
+
+        drop(guard);
+
+        // The orphaned grandchild may linger as a zombie until init reaps it
+        let state = Command::new("ps")
+            .args(["-o", "stat=", "-p", &grandchild.to_string()])
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+            .unwrap_or_default();
+        assert!(state.is_empty() || state.starts_with('Z'), "{}", state);
+    }
+
+    #[test]
+    fn test_child_guard_wait_with_output_unregisters() {
+        let guard = ChildGuard::spawn(
+            Command::new("echo")
+                .arg("done")
+                .stdout(std::process::Stdio::piped()),
+        )
+        .unwrap();
+        let pid = guard.pid;
+
+        let output = guard.wait_with_output().unwrap();
+
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "done");
+        assert!(!live_pids().lock().unwrap().contains(&pid));
+    }
+    #[test]
+    fn test_child_guard_output_captures_both_streams() {
+        let output = ChildGuard::output(
+            Command::new("sh")
+                .arg("-c")
+                .arg("echo out; echo err >&2; exit 3"),
+        )
+        .unwrap();
+
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "out");
+        assert_eq!(String::from_utf8_lossy(&output.stderr).trim(), "err");
+        assert_eq!(output.status.code(), Some(3));
+    }
+}
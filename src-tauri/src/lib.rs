@@ -2,22 +2,46 @@ mod commands;
 mod parsers;
 
 use commands::{
-    add_recent_project, analyze_bloat, analyze_dependencies, analyze_toolchains, check_all_audits,
-    check_all_licenses, check_all_outdated, check_audit, check_homebrew_status, check_licenses,
-    check_outdated, check_required_tools, check_rust_homebrew_status, clean_project,
-    clean_project_smart, clean_projects, clean_projects_smart, detect_github_actions,
-    estimate_clean_sizes, get_disk_space, detect_installed_ides, generate_docs, get_binary_sizes,
-    get_cache, get_cargo_features, get_default_scan_root, get_favorites, get_git_info,
-    get_git_stats, get_git_tags, get_github_actions_status, get_hidden, get_msrv,
-    get_preferred_ide, get_recent_projects, get_rust_version_info, get_scan_root,
-    get_workspace_info, global_search, install_tool, open_file_in_ide, open_file_in_vscode,
-    open_in_finder, open_in_ide, open_in_vscode, parse_nextest_junit, read_cargo_toml,
-    read_tarpaulin_results, run_cargo_bench, run_cargo_build, run_cargo_check, run_cargo_clippy,
-    run_cargo_command, run_cargo_command_streaming, run_cargo_doc, run_cargo_fmt_check,
-    run_cargo_run, run_cargo_tarpaulin, run_cargo_test, run_cargo_tree, run_cargo_update,
+    add_recent_project, add_recent_search, add_scan_root, analyze_bloat, analyze_dependencies,
+    analyze_dependency_weight, analyze_toolchains, audit_manifest_metadata, changes_since_last_tag,
+    check_all_audits, check_all_licenses, check_all_outdated, check_audit,
+    check_crate_name_available, check_each_feature, check_examples, check_homebrew_status,
+    check_licenses, check_lockfile_status, check_missing_docs, check_outdated,
+    check_required_targets, check_required_tools, check_rust_homebrew_status, check_semver,
+    check_workspace_msrv_consistency, clean_project, clean_project_smart, clean_projects,
+    clean_projects_smart, clean_target_subdirs, count_lines, count_unsafe_usage,
+    detect_actual_msrv, detect_git_hooks, detect_github_actions, detect_installed_ides,
+    detect_vendored_crates, detect_xtask, diff_scans, estimate_clean_sizes,
+    find_common_vulnerable_deps, find_duplicate_projects, find_git_dependencies, find_old_editions,
+    find_orphaned_targets, find_untested_projects, find_workspace_root, format_file, generate_docs,
+    generate_update_report_markdown, get_archival_suggestions, get_archive_after_days,
+    get_binary_sizes, get_build_profiles, get_build_script_info, get_build_time_history,
+    get_buildability_matrix, get_cache, get_cargo_aliases, get_cargo_features,
+    get_crate_attributes, get_crate_metadata, get_crate_release_notes_url, get_default_run_target,
+    get_default_scan_root, get_deny_config, get_disk_space, get_disk_usage_history,
+    get_docsrs_status, get_duplication_summary, get_favorites, get_fmt_diff, get_git_info,
+    get_git_stats, get_git_tags, get_github_actions_status, get_hidden, get_host_triple,
+    get_installed_targets, get_issue_tracker_url, get_latest_workflow_run, get_lint_configs,
+    get_lints_config, get_max_parallel_jobs, get_msrv, get_panic_strategy, get_preferred_ide,
+    get_project_analysis_times, get_project_license, get_recent_projects, get_recent_search_limit,
+    get_recent_searches, get_release_history, get_resolved_dependencies, get_resolved_features,
+    get_resolver_info, get_rust_version_info, get_scan_root, get_scan_roots, get_target_breakdown,
+    get_transitive_dep_count, get_update_recommendations, get_workspace_info, global_search,
+    global_search_streaming, install_missing_tools_streaming, install_target, install_tool,
+    list_benches, list_cargo_targets, measure_build_time, open_crate_releases,
+    open_dependency_source, open_file_in_ide, open_file_in_vscode, open_github_actions,
+    open_in_finder, open_in_ide, open_in_vscode, open_issues, open_projects_in_ide, open_url,
+    parse_changelog, parse_nextest_junit, project_fingerprint, read_cargo_toml,
+    read_criterion_results, read_file_range, read_tarpaulin_results, record_disk_usage,
+    remove_scan_root, reorder_favorites, run_cargo_alias, run_cargo_bench, run_cargo_build,
+    run_cargo_check, run_cargo_clippy, run_cargo_command, run_cargo_command_streaming,
+    run_cargo_doc, run_cargo_fmt_check, run_cargo_run, run_cargo_subcommand, run_cargo_tarpaulin,
+    run_cargo_test, run_cargo_tree, run_cargo_update, run_nextest_streaming, run_tests_batch,
     save_audit_cache, save_dep_analysis_cache, save_license_cache, save_outdated_cache,
-    save_toolchain_cache, scan_projects, set_favorite, set_hidden, set_preferred_ide,
-    set_scan_root, upgrade_homebrew, upgrade_rust_homebrew,
+    save_toolchain_cache, scan_projects, set_archive_after_days, set_favorite, set_hidden,
+    set_max_parallel_jobs, set_preferred_ide, set_recent_search_limit, set_scan_root,
+    suggest_version_pins, summarize_audits, summarize_features, upgrade_homebrew,
+    upgrade_rust_homebrew, validate_path_dependencies, validate_workspace,
 };
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -96,6 +120,7 @@ pub fn run() {
             open_file_in_vscode,
             get_rust_version_info,
             global_search,
+            global_search_streaming,
             check_homebrew_status,
             upgrade_homebrew,
             check_rust_homebrew_status,
@@ -111,8 +136,109 @@ pub fn run() {
             get_preferred_ide,
             set_preferred_ide,
             parse_nextest_junit,
-            detect_github_actions
+            detect_github_actions,
+            read_file_range,
+            get_recent_searches,
+            add_recent_search,
+            get_recent_search_limit,
+            set_recent_search_limit,
+            reorder_favorites,
+            run_cargo_subcommand,
+            count_lines,
+            list_cargo_targets,
+            get_crate_metadata,
+            check_crate_name_available,
+            run_nextest_streaming,
+            get_scan_roots,
+            add_scan_root,
+            remove_scan_root,
+            diff_scans,
+            open_url,
+            open_github_actions,
+            get_latest_workflow_run,
+            detect_git_hooks,
+            get_lint_configs,
+            get_fmt_diff,
+            measure_build_time,
+            get_build_time_history,
+            get_target_breakdown,
+            clean_target_subdirs,
+            install_missing_tools_streaming,
+            get_update_recommendations,
+            generate_update_report_markdown,
+            find_git_dependencies,
+            validate_path_dependencies,
+            get_resolver_info,
+            get_lints_config,
+            check_semver,
+            get_transitive_dep_count,
+            get_default_run_target,
+            analyze_dependency_weight,
+            find_duplicate_projects,
+            get_duplication_summary,
+            get_archival_suggestions,
+            get_archive_after_days,
+            set_archive_after_days,
+            open_projects_in_ide,
+            get_issue_tracker_url,
+            open_issues,
+            project_fingerprint,
+            find_old_editions,
+            get_max_parallel_jobs,
+            set_max_parallel_jobs,
+            parse_changelog,
+            get_release_history,
+            changes_since_last_tag,
+            get_build_script_info,
+            validate_workspace,
+            summarize_features,
+            audit_manifest_metadata,
+            check_workspace_msrv_consistency,
+            open_dependency_source,
+            find_untested_projects,
+            run_tests_batch,
+            check_lockfile_status,
+            get_deny_config,
+            count_unsafe_usage,
+            get_crate_attributes,
+            check_missing_docs,
+            get_docsrs_status,
+            list_benches,
+            read_criterion_results,
+            check_each_feature,
+            get_host_triple,
+            get_installed_targets,
+            install_target,
+            check_required_targets,
+            get_resolved_features,
+            suggest_version_pins,
+            format_file,
+            get_project_license,
+            get_buildability_matrix,
+            record_disk_usage,
+            get_disk_usage_history,
+            summarize_audits,
+            find_common_vulnerable_deps,
+            get_resolved_dependencies,
+            get_crate_release_notes_url,
+            open_crate_releases,
+            find_orphaned_targets,
+            get_panic_strategy,
+            get_build_profiles,
+            check_examples,
+            detect_actual_msrv,
+            get_cargo_aliases,
+            run_cargo_alias,
+            detect_xtask,
+            get_project_analysis_times,
+            find_workspace_root,
+            detect_vendored_crates
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                commands::process::terminate_all_children();
+            }
+        });
 }
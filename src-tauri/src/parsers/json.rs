@@ -1,6 +1,7 @@
 //! JSON parsing functions for cargo and brew outputs
 
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 // ============ Outdated Dependencies ============
 
@@ -255,6 +256,623 @@ pub fn parse_brew_info_json(json_str: &str) -> Option<BrewVersionInfo> {
     })
 }
 
+// ============ Cargo Targets ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct CargoTargets {
+    pub bins: Vec<String>,
+    pub examples: Vec<String>,
+    pub benches: Vec<String>,
+    pub integration_tests: Vec<String>,
+}
+
+impl CargoTargets {
+    /// Sort each list and drop duplicates
+    pub fn normalize(&mut self) {
+        for list in [
+            &mut self.bins,
+            &mut self.examples,
+            &mut self.benches,
+            &mut self.integration_tests,
+        ] {
+            list.sort();
+            list.dedup();
+        }
+    }
+}
+
+/// Parse `cargo metadata --no-deps --format-version 1` output into the targets of the
+/// package at `manifest_path`, or of every package if none matches (e.g. a virtual workspace)
+pub fn parse_cargo_metadata_targets(
+    json_str: &str,
+    manifest_path: &str,
+) -> Result<CargoTargets, String> {
+    let json: serde_json::Value =
+        serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {}", e))?;
+    let packages = json
+        .get("packages")
+        .and_then(|p| p.as_array())
+        .ok_or("Missing packages in cargo metadata output")?;
+
+    let matching: Vec<&serde_json::Value> = packages
+        .iter()
+        .filter(|p| p.get("manifest_path").and_then(|m| m.as_str()) == Some(manifest_path))
+        .collect();
+    let selected: Vec<&serde_json::Value> = if matching.is_empty() {
+        packages.iter().collect()
+    } else {
+        matching
+    };
+
+    let mut targets = CargoTargets::default();
+    for target in selected
+        .iter()
+        .filter_map(|p| p.get("targets").and_then(|t| t.as_array()))
+        .flatten()
+    {
+        let Some(name) = target.get("name").and_then(|n| n.as_str()) else {
+            continue;
+        };
+        let kinds = target
+            .get("kind")
+            .and_then(|k| k.as_array())
+            .map(|k| k.iter().filter_map(|v| v.as_str()).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        for kind in kinds {
+            let list = match kind {
+                "bin" => &mut targets.bins,
+                "example" => &mut targets.examples,
+                "bench" => &mut targets.benches,
+                "test" => &mut targets.integration_tests,
+                _ => continue,
+            };
+            list.push(name.to_string());
+        }
+    }
+
+    targets.normalize();
+    Ok(targets)
+}
+
+// ============ Dependency Graph ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct TransitiveDepCount {
+    /// Packages the project depends on directly, across all dependency kinds
+    pub direct: usize,
+    /// Every distinct package reachable from the project, direct ones included
+    pub transitive_unique: usize,
+}
+
+/// How much of the dependency graph a direct dependency brings in
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DepWeight {
+    pub name: String,
+    /// Distinct crates reachable through this dependency, not counting itself
+    pub transitive_count: usize,
+    /// Crates that would leave the build if this dependency were dropped, itself included
+    pub exclusive_count: usize,
+}
+
+/// A resolved dependency graph keyed by package id
+#[derive(Debug, Clone, Default)]
+pub struct ResolveGraph {
+    /// The packages whose dependencies are being analyzed
+    pub roots: Vec<String>,
+    pub edges: HashMap<String, Vec<String>>,
+    /// Package names by id; ids without an entry are shown as-is
+    pub names: HashMap<String, String>,
+}
+
+impl ResolveGraph {
+    fn neighbours(&self, id: &str) -> &[String] {
+        self.edges.get(id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    fn name<'a>(&'a self, id: &'a str) -> &'a str {
+        self.names.get(id).map(String::as_str).unwrap_or(id)
+    }
+
+    /// Direct dependencies of the roots, excluding the roots themselves
+    fn direct_deps(&self) -> Vec<&str> {
+        let mut direct: Vec<&str> = self
+            .roots
+            .iter()
+            .flat_map(|root| self.neighbours(root))
+            .map(String::as_str)
+            .filter(|id| !self.roots.iter().any(|r| r == id))
+            .collect();
+        direct.sort();
+        direct.dedup();
+        direct
+    }
+
+    /// Everything reachable from `starts`, the starts included. Edges from a root
+    /// to `cut` are ignored, as if the root stopped depending on it directly.
+    fn reachable<'a>(&'a self, starts: &[&'a str], cut: Option<&str>) -> HashSet<&'a str> {
+        let mut seen: HashSet<&str> = starts.iter().copied().collect();
+        let mut stack: Vec<&str> = starts.to_vec();
+        while let Some(id) = stack.pop() {
+            let is_root = self.roots.iter().any(|r| r == id);
+            for dep in self.neighbours(id) {
+                if is_root && Some(dep.as_str()) == cut {
+                    continue;
+                }
+                if seen.insert(dep.as_str()) {
+                    stack.push(dep.as_str());
+                }
+            }
+        }
+        seen
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataGraph {
+    packages: Vec<MetadataPackage>,
+    workspace_members: Vec<String>,
+    resolve: Option<MetadataResolve>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataPackage {
+    id: String,
+    #[serde(default)]
+    name: Option<String>,
+    manifest_path: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataResolve {
+    nodes: Vec<MetadataNode>,
+    root: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MetadataNode {
+    id: String,
+    dependencies: Vec<String>,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+impl MetadataGraph {
+    /// The package at `manifest_path`, else the workspace root, else every member
+    /// of a virtual workspace
+    fn roots(&self, manifest_path: &str) -> Vec<String> {
+        match self
+            .packages
+            .iter()
+            .find(|p| p.manifest_path == manifest_path)
+        {
+            Some(package) => vec![package.id.clone()],
+            None => match self.resolve.as_ref().and_then(|r| r.root.clone()) {
+                Some(root) => vec![root],
+                None => self.workspace_members.clone(),
+            },
+        }
+    }
+}
+
+/// Build the resolve graph for the package at `manifest_path` from full `cargo metadata`
+/// output. Falls back to the workspace root, or all members of a virtual workspace.
+pub fn parse_cargo_metadata_graph(
+    json_str: &str,
+    manifest_path: &str,
+) -> Result<ResolveGraph, String> {
+    let graph: MetadataGraph =
+        serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {}", e))?;
+    let roots = graph.roots(manifest_path);
+    let resolve = graph
+        .resolve
+        .ok_or("Missing resolve graph in cargo metadata output")?;
+
+    Ok(ResolveGraph {
+        roots,
+        edges: resolve
+            .nodes
+            .into_iter()
+            .map(|n| (n.id, n.dependencies))
+            .collect(),
+        names: graph
+            .packages
+            .into_iter()
+            .filter_map(|p| Some((p.id, p.name?)))
+            .collect(),
+    })
+}
+
+/// Features enabled on the package at `manifest_path` once cargo has unified the
+/// features every dependent asks for. A virtual workspace has no single package.
+pub fn parse_resolved_features(json_str: &str, manifest_path: &str) -> Result<Vec<String>, String> {
+    let graph: MetadataGraph =
+        serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {}", e))?;
+    let [root] = graph
+        .roots(manifest_path)
+        .try_into()
+        .map_err(|_| "No root package: pick a workspace member to see its features".to_string())?;
+    let resolve = graph
+        .resolve
+        .ok_or("Missing resolve graph in cargo metadata output")?;
+
+    let mut features = resolve
+        .nodes
+        .into_iter()
+        .find(|n| n.id == root)
+        .map(|n| n.features)
+        .ok_or_else(|| format!("{} is missing from the resolve graph", root))?;
+    features.sort();
+    Ok(features)
+}
+
+/// Count a project's dependencies. Packages reached along several paths count once.
+pub fn count_dependencies(graph: &ResolveGraph) -> TransitiveDepCount {
+    let roots: Vec<&str> = graph.roots.iter().map(String::as_str).collect();
+    let root_count = roots.iter().collect::<HashSet<_>>().len();
+    TransitiveDepCount {
+        direct: graph.direct_deps().len(),
+        transitive_unique: graph.reachable(&roots, None).len() - root_count,
+    }
+}
+
+/// Weigh each direct dependency by the crates it pulls in, heaviest first
+pub fn dependency_weights(graph: &ResolveGraph) -> Vec<DepWeight> {
+    let roots: Vec<&str> = graph.roots.iter().map(String::as_str).collect();
+    let everything = graph.reachable(&roots, None).len();
+
+    let mut weights: Vec<DepWeight> = graph
+        .direct_deps()
+        .into_iter()
+        .map(|dep| DepWeight {
+            name: graph.name(dep).to_string(),
+            transitive_count: graph.reachable(&[dep], None).len() - 1,
+            exclusive_count: everything - graph.reachable(&roots, Some(dep)).len(),
+        })
+        .collect();
+
+    weights.sort_by(|a, b| {
+        b.transitive_count
+            .cmp(&a.transitive_count)
+            .then(b.exclusive_count.cmp(&a.exclusive_count))
+            .then(a.name.cmp(&b.name))
+    });
+    weights
+}
+
+// ============ Compiler Messages ============
+
+/// A rustc diagnostic from a `compiler-message`, located at its primary span
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompilerDiagnostic {
+    pub level: String,
+    pub message: String,
+    pub code: Option<String>,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    /// The human-readable rendering rustc would have printed
+    pub rendered: Option<String>,
+    /// Target being compiled when rustc emitted this, e.g. an example's name
+    #[serde(default)]
+    pub target_name: Option<String>,
+    #[serde(default)]
+    pub target_kind: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompilerArtifact {
+    pub package_id: String,
+    pub target_name: String,
+    pub target_kind: Vec<String>,
+    pub filenames: Vec<String>,
+    /// True when the artifact was up to date and not rebuilt
+    pub fresh: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct CargoMessages {
+    pub diagnostics: Vec<CompilerDiagnostic>,
+    pub artifacts: Vec<CompilerArtifact>,
+    /// From the final `build-finished` message, if cargo got that far
+    pub build_success: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "reason", rename_all = "kebab-case")]
+enum CargoJsonLine {
+    CompilerMessage {
+        message: RustcMessage,
+        target: Option<ArtifactTarget>,
+    },
+    CompilerArtifact {
+        package_id: String,
+        target: ArtifactTarget,
+        #[serde(default)]
+        filenames: Vec<String>,
+        #[serde(default)]
+        fresh: bool,
+    },
+    BuildFinished {
+        success: bool,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct RustcMessage {
+    level: String,
+    message: String,
+    code: Option<RustcCode>,
+    #[serde(default)]
+    spans: Vec<RustcSpan>,
+    rendered: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RustcCode {
+    code: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RustcSpan {
+    file_name: String,
+    line_start: u32,
+    column_start: u32,
+    is_primary: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArtifactTarget {
+    name: String,
+    #[serde(default)]
+    kind: Vec<String>,
+}
+
+/// Parse `--message-format=json` output. Lines that aren't cargo JSON messages (such as
+/// test harness output, which shares stdout) are skipped, as are rustc's "aborting due
+/// to N previous errors" summaries.
+pub fn parse_cargo_json_messages(stdout: &str) -> CargoMessages {
+    let mut messages = CargoMessages::default();
+
+    for line in stdout.lines().filter(|l| l.trim_start().starts_with('{')) {
+        let Ok(parsed) = serde_json::from_str::<CargoJsonLine>(line) else {
+            continue;
+        };
+        match parsed {
+            CargoJsonLine::CompilerMessage { message, target } => {
+                if message.spans.is_empty() && message.message.starts_with("aborting due to") {
+                    continue;
+                }
+                let primary = message.spans.iter().find(|s| s.is_primary);
+                messages.diagnostics.push(CompilerDiagnostic {
+                    level: message.level,
+                    message: message.message,
+                    code: message.code.map(|c| c.code),
+                    file: primary.map(|s| s.file_name.clone()),
+                    line: primary.map(|s| s.line_start),
+                    column: primary.map(|s| s.column_start),
+                    rendered: message.rendered,
+                    target_name: target.as_ref().map(|t| t.name.clone()),
+                    target_kind: target.map(|t| t.kind).unwrap_or_default(),
+                });
+            }
+            CargoJsonLine::CompilerArtifact {
+                package_id,
+                target,
+                filenames,
+                fresh,
+            } => messages.artifacts.push(CompilerArtifact {
+                package_id,
+                target_name: target.name,
+                target_kind: target.kind,
+                filenames,
+                fresh,
+            }),
+            CargoJsonLine::BuildFinished { success } => messages.build_success = Some(success),
+            CargoJsonLine::Other => {}
+        }
+    }
+
+    messages
+}
+
+// ============ Crates.io ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct CrateMeta {
+    pub name: String,
+    pub downloads: u64,
+    pub recent_downloads: Option<u64>,
+    pub latest_version: String,
+    pub max_stable: Option<String>,
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    pub documentation: Option<String>,
+    #[serde(default)]
+    pub repository: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesIoResponse {
+    #[serde(rename = "crate")]
+    krate: CratesIoCrate,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesIoCrate {
+    name: String,
+    downloads: u64,
+    recent_downloads: Option<u64>,
+    newest_version: Option<String>,
+    max_version: String,
+    max_stable_version: Option<String>,
+    description: Option<String>,
+    homepage: Option<String>,
+    documentation: Option<String>,
+    #[serde(default)]
+    repository: Option<String>,
+}
+
+/// Parse a crates.io `/api/v1/crates/{name}` response
+pub fn parse_crate_meta(json_str: &str) -> Result<CrateMeta, String> {
+    let parsed: CratesIoResponse =
+        serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {}", e))?;
+    let krate = parsed.krate;
+
+    Ok(CrateMeta {
+        name: krate.name,
+        downloads: krate.downloads,
+        recent_downloads: krate.recent_downloads,
+        latest_version: krate.newest_version.unwrap_or(krate.max_version),
+        max_stable: krate.max_stable_version,
+        description: krate.description.map(|d| d.trim().to_string()),
+        homepage: krate.homepage,
+        documentation: krate.documentation,
+        repository: krate.repository,
+    })
+}
+
+// ============ Criterion ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchResult {
+    pub name: String,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub std_dev_ns: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct CriterionEstimate {
+    point_estimate: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct CriterionEstimates {
+    mean: CriterionEstimate,
+    median: CriterionEstimate,
+    std_dev: CriterionEstimate,
+}
+
+/// Parse a criterion `estimates.json`. Criterion records times in nanoseconds.
+pub fn parse_criterion_estimates(name: &str, json_str: &str) -> Result<BenchResult, String> {
+    let parsed: CriterionEstimates =
+        serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {}", e))?;
+
+    Ok(BenchResult {
+        name: name.to_string(),
+        mean_ns: parsed.mean.point_estimate,
+        median_ns: parsed.median.point_estimate,
+        std_dev_ns: parsed.std_dev.point_estimate,
+    })
+}
+
+// ============ docs.rs ============
+
+pub const DOCS_RS_URL: &str = "https://docs.rs";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DocsRsStatus {
+    /// "success" or "failure"
+    pub build_status: String,
+    /// The version docs.rs resolved, e.g. "1.0.200" for a "latest" request
+    pub version: String,
+    pub doc_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DocsRsStatusResponse {
+    version: String,
+    doc_status: bool,
+}
+
+/// Parse a docs.rs `/crate/{name}/{version}/status.json` response. Failed builds
+/// link to the build log list instead of the docs.
+pub fn parse_docsrs_status(name: &str, json_str: &str) -> Result<DocsRsStatus, String> {
+    let parsed: DocsRsStatusResponse =
+        serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {}", e))?;
+
+    let (build_status, doc_url) = if parsed.doc_status {
+        (
+            "success",
+            format!("{}/{}/{}", DOCS_RS_URL, name, parsed.version),
+        )
+    } else {
+        (
+            "failure",
+            format!("{}/crate/{}/{}/builds", DOCS_RS_URL, name, parsed.version),
+        )
+    };
+
+    Ok(DocsRsStatus {
+        build_status: build_status.to_string(),
+        version: parsed.version,
+        doc_url,
+    })
+}
+
+// ============ GitHub Actions ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowRun {
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub html_url: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkflowRunsResponse {
+    workflow_runs: Vec<WorkflowRun>,
+}
+
+/// Parse a GitHub `/actions/runs` response and return the most recent run, if any
+pub fn parse_workflow_runs_json(json_str: &str) -> Result<Option<WorkflowRun>, String> {
+    let parsed: WorkflowRunsResponse =
+        serde_json::from_str(json_str).map_err(|e| format!("JSON parse error: {}", e))?;
+    Ok(parsed.workflow_runs.into_iter().next())
+}
+
+// ============ cargo-msrv ============
+
+/// `result.version` from the first object (depth first) that has one
+fn msrv_result_version(value: &serde_json::Value) -> Option<String> {
+    let object = value.as_object()?;
+    if let Some(version) = object
+        .get("result")
+        .and_then(|r| r.get("version"))
+        .and_then(|v| v.as_str())
+    {
+        return Some(version.to_string());
+    }
+    object.values().find_map(msrv_result_version)
+}
+
+/// The MSRV from `cargo msrv --output-format json`, which prints one JSON event per line.
+/// Current releases end with a `subcommand_result` event carrying `result.version`; older
+/// ones with `{"reason": "msrv-complete", "msrv": ...}`. Ok(None) means cargo-msrv
+/// finished without finding a toolchain that builds the crate.
+pub fn parse_cargo_msrv_json(output: &str) -> Result<Option<String>, String> {
+    for line in output
+        .lines()
+        .rev()
+        .filter(|l| l.trim_start().starts_with('{'))
+    {
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if event.get("type").and_then(|t| t.as_str()) == Some("subcommand_result") {
+            return Ok(msrv_result_version(&event));
+        }
+        if event.get("reason").and_then(|r| r.as_str()) == Some("msrv-complete") {
+            return Ok(event.get("msrv").and_then(|m| m.as_str()).map(String::from));
+        }
+    }
+    Err("cargo-msrv did not report a result".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -535,4 +1153,453 @@ mod tests {
         let info = parse_brew_info_json(json);
         assert!(info.is_none());
     }
+
+    // ============ Cargo Metadata Targets Parser Tests ============
+
+    const METADATA_FIXTURE: &str = r#"{
+        "packages": [
+            {
+                "name": "demo",
+                "manifest_path": "/projects/demo/Cargo.toml",
+                "targets": [
+                    {"name": "demo", "kind": ["lib"], "src_path": "/projects/demo/src/lib.rs"},
+                    {"name": "demo", "kind": ["bin"], "src_path": "/projects/demo/src/main.rs"},
+                    {"name": "hello", "kind": ["example"], "src_path": "/projects/demo/examples/hello.rs"},
+                    {"name": "speed", "kind": ["bench"], "src_path": "/projects/demo/benches/speed.rs"},
+                    {"name": "api", "kind": ["test"], "src_path": "/projects/demo/tests/api.rs"},
+                    {"name": "build-script-build", "kind": ["custom-build"], "src_path": "/projects/demo/build.rs"}
+                ]
+            },
+            {
+                "name": "other",
+                "manifest_path": "/projects/demo/other/Cargo.toml",
+                "targets": [
+                    {"name": "other-cli", "kind": ["bin"], "src_path": "/projects/demo/other/src/main.rs"}
+                ]
+            }
+        ],
+        "workspace_members": []
+    }"#;
+
+    #[test]
+    fn test_parse_cargo_metadata_targets_selects_package() {
+        let targets =
+            parse_cargo_metadata_targets(METADATA_FIXTURE, "/projects/demo/Cargo.toml").unwrap();
+        assert_eq!(targets.bins, vec!["demo"]);
+        assert_eq!(targets.examples, vec!["hello"]);
+        assert_eq!(targets.benches, vec!["speed"]);
+        assert_eq!(targets.integration_tests, vec!["api"]);
+    }
+
+    #[test]
+    fn test_parse_cargo_metadata_targets_virtual_workspace() {
+        let targets =
+            parse_cargo_metadata_targets(METADATA_FIXTURE, "/projects/virtual/Cargo.toml").unwrap();
+        assert_eq!(targets.bins, vec!["demo", "other-cli"]);
+    }
+
+    #[test]
+    fn test_parse_cargo_metadata_targets_invalid() {
+        assert!(parse_cargo_metadata_targets("not json", "/x/Cargo.toml").is_err());
+        assert!(parse_cargo_metadata_targets("{}", "/x/Cargo.toml").is_err());
+    }
+
+    // ============ Dependency Graph Parser Tests ============
+
+    // app -> (serde, tokio), tokio -> (bytes, mio), mio -> (libc), serde -> (serde_derive),
+    // serde_derive -> (syn), plus a second workspace member that also uses libc
+    const RESOLVE_FIXTURE: &str = r#"{
+        "packages": [
+            {"id": "app 0.1.0 (path+file:///ws/app)", "manifest_path": "/ws/app/Cargo.toml"},
+            {"id": "tool 0.1.0 (path+file:///ws/tool)", "manifest_path": "/ws/tool/Cargo.toml"}
+        ],
+        "workspace_members": [
+            "app 0.1.0 (path+file:///ws/app)",
+            "tool 0.1.0 (path+file:///ws/tool)"
+        ],
+        "resolve": {
+            "root": null,
+            "nodes": [
+                {"id": "app 0.1.0 (path+file:///ws/app)", "dependencies": ["serde 1.0.0", "tokio 1.0.0"]},
+                {"id": "tool 0.1.0 (path+file:///ws/tool)", "dependencies": ["libc 0.2.0", "app 0.1.0 (path+file:///ws/app)"]},
+                {"id": "serde 1.0.0", "dependencies": ["serde_derive 1.0.0"]},
+                {"id": "serde_derive 1.0.0", "dependencies": ["syn 2.0.0"]},
+                {"id": "syn 2.0.0", "dependencies": []},
+                {"id": "tokio 1.0.0", "dependencies": ["bytes 1.0.0", "mio 0.8.0"]},
+                {"id": "bytes 1.0.0", "dependencies": []},
+                {"id": "mio 0.8.0", "dependencies": ["libc 0.2.0"]},
+                {"id": "libc 0.2.0", "dependencies": []}
+            ]
+        }
+    }"#;
+
+    fn dep_count(manifest_path: &str) -> TransitiveDepCount {
+        count_dependencies(&parse_cargo_metadata_graph(RESOLVE_FIXTURE, manifest_path).unwrap())
+    }
+
+    #[test]
+    fn test_count_dependencies() {
+        let count = dep_count("/ws/app/Cargo.toml");
+        assert_eq!(
+            count,
+            TransitiveDepCount {
+                direct: 2,
+                transitive_unique: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn test_count_dependencies_counts_shared_deps_once() {
+        // tool reaches libc directly and through app -> tokio -> mio
+        let count = dep_count("/ws/tool/Cargo.toml");
+        assert_eq!(count.direct, 2);
+        assert_eq!(count.transitive_unique, 8);
+
+        // Virtual workspace: members aren't counted as dependencies of each other
+        let count = dep_count("/ws/Cargo.toml");
+        assert_eq!(count.direct, 3);
+        assert_eq!(count.transitive_unique, 7);
+    }
+
+    #[test]
+    fn test_parse_resolved_features() {
+        // app only enables `default` itself; tool turns on `json` through its dependency
+        let json = r#"{
+            "packages": [
+                {"id": "app 0.1.0 (path+file:///ws/app)", "name": "app", "manifest_path": "/ws/app/Cargo.toml"},
+                {"id": "tool 0.1.0 (path+file:///ws/tool)", "name": "tool", "manifest_path": "/ws/tool/Cargo.toml"},
+                {"id": "serde 1.0.0", "name": "serde", "manifest_path": "/registry/serde/Cargo.toml"}
+            ],
+            "workspace_members": [
+                "app 0.1.0 (path+file:///ws/app)",
+                "tool 0.1.0 (path+file:///ws/tool)"
+            ],
+            "resolve": {
+                "root": null,
+                "nodes": [
+                    {"id": "app 0.1.0 (path+file:///ws/app)", "dependencies": ["serde 1.0.0"], "features": ["json", "default"]},
+                    {"id": "tool 0.1.0 (path+file:///ws/tool)", "dependencies": ["app 0.1.0 (path+file:///ws/app)"], "features": []},
+                    {"id": "serde 1.0.0", "dependencies": [], "features": ["default", "derive", "std"]}
+                ]
+            }
+        }"#;
+        assert_eq!(
+            parse_resolved_features(json, "/ws/app/Cargo.toml").unwrap(),
+            vec!["default", "json"]
+        );
+        assert!(parse_resolved_features(json, "/ws/tool/Cargo.toml")
+            .unwrap()
+            .is_empty());
+        // The virtual workspace root has no package of its own
+        assert!(parse_resolved_features(json, "/ws/Cargo.toml").is_err());
+    }
+
+    #[test]
+    fn test_parse_cargo_metadata_graph_invalid() {
+        assert!(parse_cargo_metadata_graph("not json", "/x/Cargo.toml").is_err());
+        let no_resolve = r#"{"packages": [], "workspace_members": [], "resolve": null}"#;
+        assert!(parse_cargo_metadata_graph(no_resolve, "/x/Cargo.toml").is_err());
+    }
+
+    fn graph(roots: &[&str], edges: &[(&str, &[&str])]) -> ResolveGraph {
+        ResolveGraph {
+            roots: roots.iter().map(|r| r.to_string()).collect(),
+            edges: edges
+                .iter()
+                .map(|(id, deps)| (id.to_string(), deps.iter().map(|d| d.to_string()).collect()))
+                .collect(),
+            names: HashMap::new(),
+        }
+    }
+
+    fn weight(name: &str, transitive_count: usize, exclusive_count: usize) -> DepWeight {
+        DepWeight {
+            name: name.to_string(),
+            transitive_count,
+            exclusive_count,
+        }
+    }
+
+    #[test]
+    fn test_dependency_weights_diamond() {
+        // Diamond: both b and c lead to d (and e), so neither owns them exclusively
+        let diamond = graph(
+            &["app"],
+            &[
+                ("app", &["b", "c", "f"]),
+                ("b", &["d"]),
+                ("c", &["d"]),
+                ("d", &["e"]),
+                ("f", &["g"]),
+            ],
+        );
+        assert_eq!(
+            dependency_weights(&diamond),
+            vec![weight("b", 2, 1), weight("c", 2, 1), weight("f", 1, 2)]
+        );
+    }
+
+    #[test]
+    fn test_dependency_weights_direct_dep_also_reached_transitively() {
+        // Dropping the direct edge to d keeps it in the build through b
+        let g = graph(
+            &["app"],
+            &[("app", &["b", "d"]), ("b", &["d"]), ("d", &["e"])],
+        );
+        assert_eq!(
+            dependency_weights(&g),
+            vec![weight("b", 2, 1), weight("d", 1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_dependency_weights_uses_package_names() {
+        let mut g = graph(&["app"], &[("app", &["serde 1.0.0 (registry)"])]);
+        g.names
+            .insert("serde 1.0.0 (registry)".to_string(), "serde".to_string());
+        assert_eq!(dependency_weights(&g), vec![weight("serde", 0, 1)]);
+    }
+
+    // ============ Compiler Messages Parser Tests ============
+
+    #[test]
+    fn test_parse_cargo_json_messages_mixed_stream() {
+        let stdout = r#"{"reason":"compiler-artifact","package_id":"libc 0.2.150","manifest_path":"/reg/libc/Cargo.toml","target":{"kind":["lib"],"crate_types":["lib"],"name":"libc","src_path":"/reg/libc/src/lib.rs"},"profile":{},"features":[],"filenames":["/p/demo/target/debug/deps/liblibc.rlib"],"executable":null,"fresh":true}
+{"reason":"build-script-executed","package_id":"demo 0.1.0","linked_libs":[],"linked_paths":[],"cfgs":[],"env":[],"out_dir":"/p/demo/target/debug/build/demo/out"}
+{"reason":"compiler-message","package_id":"demo 0.1.0","target":{"kind":["bin"],"name":"demo"},"message":{"$message_type":"diagnostic","message":"unused variable: `x`","code":{"code":"unused_variables","explanation":null},"level":"warning","spans":[{"file_name":"src/main.rs","byte_start":20,"byte_end":21,"line_start":2,"line_end":2,"column_start":9,"column_end":10,"is_primary":true,"text":[],"label":null}],"children":[],"rendered":"warning: unused variable: `x`\n"}}
+{"reason":"compiler-message","package_id":"demo 0.1.0","target":{"kind":["bin"],"name":"demo"},"message":{"message":"mismatched types","code":{"code":"E0308","explanation":"..."},"level":"error","spans":[{"file_name":"src/lib.rs","line_start":1,"column_start":1,"is_primary":false},{"file_name":"src/main.rs","line_start":5,"column_start":13,"is_primary":true}],"children":[],"rendered":"error[E0308]: mismatched types\n"}}
+{"reason":"compiler-message","package_id":"demo 0.1.0","target":{"kind":["bin"],"name":"demo"},"message":{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[],"children":[],"rendered":"error: aborting due to 1 previous error\n"}}
+{"reason":"compiler-artifact","package_id":"demo 0.1.0","target":{"kind":["bin"],"name":"demo"},"filenames":["/p/demo/target/debug/demo"],"fresh":false}
+running 1 test
+test tests::it_works ... ok
+{"reason":"build-finished","success":false}
+"#;
+        let messages = parse_cargo_json_messages(stdout);
+
+        assert_eq!(messages.artifacts.len(), 2);
+        assert_eq!(messages.artifacts[0].target_name, "libc");
+        assert!(messages.artifacts[0].fresh);
+        assert_eq!(messages.artifacts[1].target_kind, vec!["bin"]);
+        assert_eq!(
+            messages.artifacts[1].filenames,
+            vec!["/p/demo/target/debug/demo"]
+        );
+
+        assert_eq!(messages.diagnostics.len(), 2);
+        assert_eq!(
+            messages.diagnostics[0],
+            CompilerDiagnostic {
+                level: "warning".to_string(),
+                message: "unused variable: `x`".to_string(),
+                code: Some("unused_variables".to_string()),
+                file: Some("src/main.rs".to_string()),
+                line: Some(2),
+                column: Some(9),
+                rendered: Some("warning: unused variable: `x`\n".to_string()),
+                target_name: Some("demo".to_string()),
+                target_kind: vec!["bin".to_string()],
+            }
+        );
+        assert_eq!(messages.diagnostics[1].code.as_deref(), Some("E0308"));
+        assert_eq!(messages.diagnostics[1].line, Some(5));
+        assert_eq!(messages.build_success, Some(false));
+    }
+
+    #[test]
+    fn test_parse_cargo_json_messages_plain_text() {
+        let messages = parse_cargo_json_messages("   Compiling demo v0.1.0\n{not json\n");
+        assert_eq!(messages, CargoMessages::default());
+    }
+
+    // ============ Crates.io Parser Tests ============
+
+    #[test]
+    fn test_parse_crate_meta() {
+        let json = r#"{
+            "categories": [],
+            "crate": {
+                "id": "serde",
+                "name": "serde",
+                "downloads": 412345678,
+                "recent_downloads": 61234567,
+                "max_version": "1.0.210",
+                "max_stable_version": "1.0.210",
+                "newest_version": "1.0.210",
+                "description": "A generic serialization/deserialization framework\n",
+                "homepage": "https://serde.rs",
+                "documentation": "https://docs.rs/serde",
+                "repository": "https://github.com/serde-rs/serde",
+                "created_at": "2014-12-05T20:20:39.487502+00:00"
+            },
+            "keywords": [],
+            "versions": [{"num": "1.0.210", "yanked": false}]
+        }"#;
+        let meta = parse_crate_meta(json).unwrap();
+        assert_eq!(meta.name, "serde");
+        assert_eq!(meta.downloads, 412345678);
+        assert_eq!(meta.recent_downloads, Some(61234567));
+        assert_eq!(meta.latest_version, "1.0.210");
+        assert_eq!(meta.max_stable, Some("1.0.210".to_string()));
+        assert_eq!(
+            meta.description.as_deref(),
+            Some("A generic serialization/deserialization framework")
+        );
+        assert_eq!(meta.homepage.as_deref(), Some("https://serde.rs"));
+        assert_eq!(meta.documentation.as_deref(), Some("https://docs.rs/serde"));
+        assert_eq!(
+            meta.repository.as_deref(),
+            Some("https://github.com/serde-rs/serde")
+        );
+    }
+
+    #[test]
+    fn test_parse_crate_meta_prerelease_only() {
+        let json = r#"{"crate": {
+            "name": "fresh",
+            "downloads": 12,
+            "recent_downloads": null,
+            "max_version": "0.1.0-alpha.1",
+            "max_stable_version": null,
+            "description": null,
+            "homepage": null,
+            "documentation": null
+        }}"#;
+        let meta = parse_crate_meta(json).unwrap();
+        assert_eq!(meta.latest_version, "0.1.0-alpha.1");
+        assert!(meta.max_stable.is_none());
+        assert!(meta.recent_downloads.is_none());
+        assert!(meta.repository.is_none());
+    }
+
+    #[test]
+    fn test_parse_crate_meta_not_found() {
+        let json = r#"{"errors":[{"detail":"crate `nope` does not exist"}]}"#;
+        assert!(parse_crate_meta(json).is_err());
+    }
+
+    // ============ Criterion Parser Tests ============
+
+    #[test]
+    fn test_parse_criterion_estimates() {
+        let json = r#"{
+            "mean": {"confidence_interval": {"confidence_level": 0.95, "lower_bound": 1510.2, "upper_bound": 1533.9}, "point_estimate": 1521.7, "standard_error": 6.1},
+            "median": {"confidence_interval": {"confidence_level": 0.95, "lower_bound": 1502.0, "upper_bound": 1511.3}, "point_estimate": 1507.4, "standard_error": 2.4},
+            "median_abs_dev": {"confidence_interval": {"confidence_level": 0.95, "lower_bound": 9.8, "upper_bound": 18.2}, "point_estimate": 13.9, "standard_error": 2.2},
+            "slope": null,
+            "std_dev": {"confidence_interval": {"confidence_level": 0.95, "lower_bound": 41.0, "upper_bound": 78.5}, "point_estimate": 61.3, "standard_error": 9.7}
+        }"#;
+        let result = parse_criterion_estimates("parse/small", json).unwrap();
+        assert_eq!(
+            result,
+            BenchResult {
+                name: "parse/small".to_string(),
+                mean_ns: 1521.7,
+                median_ns: 1507.4,
+                std_dev_ns: 61.3,
+            }
+        );
+
+        assert!(parse_criterion_estimates("parse/small", r#"{"mean": {}}"#).is_err());
+    }
+
+    // ============ docs.rs Parser Tests ============
+
+    #[test]
+    fn test_parse_docsrs_status() {
+        let json = r#"{"version":"1.0.210","doc_status":true}"#;
+        let status = parse_docsrs_status("serde", json).unwrap();
+        assert_eq!(status.build_status, "success");
+        assert_eq!(status.version, "1.0.210");
+        assert_eq!(status.doc_url, "https://docs.rs/serde/1.0.210");
+
+        let json = r#"{"version":"0.3.0","doc_status":false}"#;
+        let status = parse_docsrs_status("broken-sys", json).unwrap();
+        assert_eq!(status.build_status, "failure");
+        assert_eq!(
+            status.doc_url,
+            "https://docs.rs/crate/broken-sys/0.3.0/builds"
+        );
+
+        assert!(parse_docsrs_status("serde", "<html>Not Found</html>").is_err());
+    }
+
+    // ============ GitHub Actions Parser Tests ============
+
+    #[test]
+    fn test_parse_workflow_runs_json() {
+        let json = r#"{
+            "total_count": 412,
+            "workflow_runs": [
+                {
+                    "id": 11223344556,
+                    "name": "CI",
+                    "head_branch": "main",
+                    "head_sha": "a1b2c3d",
+                    "event": "push",
+                    "status": "completed",
+                    "conclusion": "success",
+                    "html_url": "https://github.com/owner/repo/actions/runs/11223344556",
+                    "created_at": "2024-10-01T12:34:56Z",
+                    "updated_at": "2024-10-01T12:40:00Z"
+                }
+            ]
+        }"#;
+        let run = parse_workflow_runs_json(json).unwrap().unwrap();
+        assert_eq!(run.status, "completed");
+        assert_eq!(run.conclusion.as_deref(), Some("success"));
+        assert_eq!(
+            run.html_url,
+            "https://github.com/owner/repo/actions/runs/11223344556"
+        );
+        assert_eq!(run.created_at, "2024-10-01T12:34:56Z");
+    }
+
+    #[test]
+    fn test_parse_workflow_runs_json_in_progress() {
+        let json = r#"{"total_count": 1, "workflow_runs": [{
+            "status": "in_progress",
+            "conclusion": null,
+            "html_url": "https://github.com/owner/repo/actions/runs/1",
+            "created_at": "2024-10-02T08:00:00Z"
+        }]}"#;
+        let run = parse_workflow_runs_json(json).unwrap().unwrap();
+        assert_eq!(run.status, "in_progress");
+        assert!(run.conclusion.is_none());
+    }
+
+    #[test]
+    fn test_parse_workflow_runs_json_empty_and_invalid() {
+        let json = r#"{"total_count": 0, "workflow_runs": []}"#;
+        assert!(parse_workflow_runs_json(json).unwrap().is_none());
+        assert!(parse_workflow_runs_json(r#"{"message": "Not Found"}"#).is_err());
+    }
+
+    // ============ cargo-msrv Parser Tests ============
+
+    #[test]
+    fn test_parse_cargo_msrv_json() {
+        let output = r#"{"type":"meta","instance":"cargo-msrv","version":"0.16.0","sha_short":"abc1234","target_directory":"/p/demo/target"}
+{"type":"unit_of_work","scope":{"id":1,"marker":"start"},"unit_of_work":{"action":"find_msrv"}}
+{"type":"check_toolchain","scope":{"id":2,"marker":"start"},"toolchain":{"version":"1.70.0","target":"x86_64-unknown-linux-gnu"}}
+{"type":"check_result","check_result":{"toolchain":{"version":"1.70.0","target":"x86_64-unknown-linux-gnu"},"is_compatible":true}}
+warning: some stray stderr line
+{"type":"subcommand_result","subcommand_result":{"result":{"version":"1.65.0"},"toolchain":{"version":"1.65.0","target":"x86_64-unknown-linux-gnu"}}}"#;
+        assert_eq!(
+            parse_cargo_msrv_json(output).unwrap().as_deref(),
+            Some("1.65.0")
+        );
+
+        let legacy = r#"{"reason":"mode","mode":"determine-msrv","toolchain":"x86_64-unknown-linux-gnu","checkCmd":"cargo check"}
+{"reason":"msrv-complete","success":true,"msrv":"1.56.1","toolchain":"x86_64-unknown-linux-gnu"}"#;
+        assert_eq!(
+            parse_cargo_msrv_json(legacy).unwrap().as_deref(),
+            Some("1.56.1")
+        );
+
+        let none_found = r#"{"type":"subcommand_result","subcommand_result":{"result":null}}"#;
+        assert_eq!(parse_cargo_msrv_json(none_found).unwrap(), None);
+
+        assert!(parse_cargo_msrv_json("error: no such command: `msrv`").is_err());
+    }
 }
@@ -4,15 +4,29 @@
 //! string/text data into structured types for the application.
 
 pub mod json;
+pub mod source;
 pub mod text;
 pub mod toml;
 pub mod xml;
 
 // Re-export commonly used parsers
 pub use json::{
-    parse_brew_info_json, parse_cargo_audit_json, parse_cargo_license_json,
-    parse_cargo_outdated_json,
+    count_dependencies, dependency_weights, parse_brew_info_json, parse_cargo_audit_json,
+    parse_cargo_json_messages, parse_cargo_license_json, parse_cargo_metadata_graph,
+    parse_cargo_metadata_targets, parse_cargo_msrv_json, parse_cargo_outdated_json,
+    parse_crate_meta, parse_criterion_estimates, parse_docsrs_status, parse_resolved_features,
+    parse_workflow_runs_json,
+};
+pub use text::{
+    parse_changelog_markdown, parse_git_log_oneline, parse_installed_targets, parse_libtest_totals,
+    parse_rustc_host, parse_rustc_version, parse_rustfmt_diff, parse_rustup_toolchain_list,
+    parse_semver_checks_output, parse_tool_version, sniff_license,
+};
+pub use toml::{
+    lint_cargo_toml, lints_inherit_workspace, parse_build_profiles, parse_build_targets,
+    parse_cargo_aliases, parse_cargo_features_toml, parse_cargo_lock, parse_cargo_registry_config,
+    parse_deny_toml, parse_lint_configs, parse_lints_toml, parse_msrv_toml, parse_project_settings,
+    parse_resolver_toml, parse_toolchain_targets, parse_vendor_directory,
+    parse_workspace_lints_toml,
 };
-pub use text::{parse_rustc_version, parse_rustup_toolchain_list};
-pub use toml::{parse_cargo_features_toml, parse_msrv_toml};
 pub use xml::parse_junit_xml;
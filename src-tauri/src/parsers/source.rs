@@ -0,0 +1,762 @@
+//! Lightweight parsing of Rust source files
+
+use serde::{Deserialize, Serialize};
+
+// ============ Lines of Code ============
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocStats {
+    pub files: usize,
+    pub code: usize,
+    pub comments: usize,
+    pub blank: usize,
+}
+
+impl LocStats {
+    pub fn add(&mut self, other: &LocStats) {
+        self.files += other.files;
+        self.code += other.code;
+        self.comments += other.comments;
+        self.blank += other.blank;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineKind {
+    Code,
+    Comment,
+    Blank,
+}
+
+/// Classifies Rust source lines, carrying block-comment and string state
+/// across lines. Anything that isn't clearly a comment or blank counts as code.
+#[derive(Debug, Default)]
+pub struct RustLineClassifier {
+    block_depth: usize,
+    in_string: bool,
+    // Number of `#`s closing the raw string we're inside, if any
+    raw_string_hashes: Option<usize>,
+}
+
+impl RustLineClassifier {
+    pub fn classify(&mut self, line: &str) -> LineKind {
+        self.scan(line).0
+    }
+
+    /// Classify a line and return its code with string/char literals and comments
+    /// blanked out, so keyword scans can't match inside them
+    pub fn scan(&mut self, line: &str) -> (LineKind, String) {
+        let chars: Vec<char> = line.chars().collect();
+        let inside_literal = self.in_string || self.raw_string_hashes.is_some();
+        let mut code = String::with_capacity(line.len());
+
+        if line.trim().is_empty() {
+            let kind = if inside_literal {
+                LineKind::Code
+            } else if self.block_depth > 0 {
+                LineKind::Comment
+            } else {
+                LineKind::Blank
+            };
+            return (kind, code);
+        }
+
+        let mut has_code = inside_literal;
+        let mut has_comment = self.block_depth > 0;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if self.in_string {
+                match c {
+                    '\\' => i += 1,
+                    '"' => {
+                        self.in_string = false;
+                        code.push(' ');
+                    }
+                    _ => {}
+                }
+                i += 1;
+                continue;
+            }
+
+            if let Some(hashes) = self.raw_string_hashes {
+                if c == '"' && chars[i + 1..].iter().take_while(|&&h| h == '#').count() >= hashes {
+                    self.raw_string_hashes = None;
+                    code.push(' ');
+                    i += hashes;
+                }
+                i += 1;
+                continue;
+            }
+
+            if self.block_depth > 0 {
+                match (c, next) {
+                    ('/', Some('*')) => {
+                        self.block_depth += 1;
+                        i += 1;
+                    }
+                    ('*', Some('/')) => {
+                        self.block_depth -= 1;
+                        if self.block_depth == 0 {
+                            code.push(' ');
+                        }
+                        i += 1;
+                    }
+                    _ => {}
+                }
+                i += 1;
+                continue;
+            }
+
+            match (c, next) {
+                ('/', Some('/')) => {
+                    has_comment = true;
+                    break;
+                }
+                ('/', Some('*')) => {
+                    has_comment = true;
+                    self.block_depth = 1;
+                    i += 2;
+                    continue;
+                }
+                ('"', _) => {
+                    has_code = true;
+                    self.in_string = true;
+                    code.push(' ');
+                }
+                ('r', Some('"' | '#')) if starts_raw_string(&chars, i) => {
+                    let hashes = chars[i + 1..].iter().take_while(|&&h| h == '#').count();
+                    if chars.get(i + 1 + hashes) == Some(&'"') {
+                        has_code = true;
+                        self.raw_string_hashes = Some(hashes);
+                        code.push(' ');
+                        i += hashes + 2;
+                        continue;
+                    }
+                    has_code = true;
+                    code.push(c);
+                }
+                ('\'', _) => {
+                    has_code = true;
+                    // Skip char literals like '"' or '\'' so they don't open a string;
+                    // anything else is a lifetime
+                    if next == Some('\\') {
+                        if let Some(end) = chars[i + 2..].iter().position(|&ch| ch == '\'') {
+                            code.push(' ');
+                            i += end + 3;
+                            continue;
+                        }
+                    } else if chars.get(i + 2) == Some(&'\'') {
+                        code.push(' ');
+                        i += 3;
+                        continue;
+                    }
+                    code.push(c);
+                }
+                (ch, _) => {
+                    has_code |= !ch.is_whitespace();
+                    code.push(ch);
+                }
+            }
+            i += 1;
+        }
+
+        let kind = if has_code {
+            LineKind::Code
+        } else if has_comment {
+            LineKind::Comment
+        } else {
+            LineKind::Blank
+        };
+        (kind, code)
+    }
+}
+
+fn is_ident_char(c: Option<&char>) -> bool {
+    c.map(|c| c.is_alphanumeric() || *c == '_').unwrap_or(false)
+}
+
+/// Whether the `r` at `i` begins a raw (or raw byte) string rather than ending an identifier
+fn starts_raw_string(chars: &[char], i: usize) -> bool {
+    let prev = i.checked_sub(1).and_then(|p| chars.get(p));
+    match prev {
+        Some('b') => !is_ident_char(i.checked_sub(2).and_then(|p| chars.get(p))),
+        _ => !is_ident_char(prev),
+    }
+}
+
+/// Count code, comment, and blank lines in a single Rust source file
+pub fn count_source_lines(content: &str) -> LocStats {
+    let mut classifier = RustLineClassifier::default();
+    let mut stats = LocStats {
+        files: 1,
+        ..Default::default()
+    };
+
+    for line in content.lines() {
+        match classifier.classify(line) {
+            LineKind::Code => stats.code += 1,
+            LineKind::Comment => stats.comments += 1,
+            LineKind::Blank => stats.blank += 1,
+        }
+    }
+
+    stats
+}
+
+// ============ Unsafe Code ============
+
+/// Approximate `unsafe` usage. Counts come from a token scan, not a parse, so macro
+/// bodies are included and unusual formatting may be missed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnsafeUsage {
+    pub unsafe_blocks: usize,
+    pub unsafe_fns: usize,
+    pub unsafe_impls: usize,
+    /// Edition 2024 `unsafe extern "C" { .. }` declaration blocks, which hold
+    /// foreign items rather than unsafe code
+    #[serde(default)]
+    pub unsafe_extern_blocks: usize,
+    pub files_with_unsafe: usize,
+}
+
+impl UnsafeUsage {
+    pub fn add(&mut self, other: &UnsafeUsage) {
+        self.unsafe_blocks += other.unsafe_blocks;
+        self.unsafe_fns += other.unsafe_fns;
+        self.unsafe_impls += other.unsafe_impls;
+        self.unsafe_extern_blocks += other.unsafe_extern_blocks;
+        self.files_with_unsafe += other.files_with_unsafe;
+    }
+}
+
+/// Count `unsafe { .. }`, `unsafe fn` (including `unsafe extern "C" fn`), `unsafe impl`
+/// and `unsafe extern` blocks in a single source file. Occurrences in strings and
+/// comments are ignored, as are attribute uses like `#[unsafe(no_mangle)]`.
+pub fn scan_unsafe_usage(content: &str) -> UnsafeUsage {
+    let mut classifier = RustLineClassifier::default();
+    let code: String = content
+        .lines()
+        .map(|line| classifier.scan(line).1)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Identifiers and single punctuation characters, in order
+    let mut tokens: Vec<&str> = Vec::new();
+    let mut start = None;
+    for (i, c) in code.char_indices() {
+        let ident = c.is_alphanumeric() || c == '_';
+        match (ident, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                tokens.push(&code[s..i]);
+                start = None;
+            }
+            _ => {}
+        }
+        if !ident && !c.is_whitespace() {
+            tokens.push(&code[i..i + c.len_utf8()]);
+        }
+    }
+    if let Some(s) = start {
+        tokens.push(&code[s..]);
+    }
+
+    let mut usage = UnsafeUsage::default();
+    for (i, _) in tokens.iter().enumerate().filter(|(_, t)| **t == "unsafe") {
+        // Skip the ABI of `unsafe extern "C" fn` (the string is already blanked)
+        let mut next = tokens[i + 1..].iter();
+        let mut following = next.next();
+        let is_extern = following == Some(&"extern");
+        if is_extern {
+            following = next.next();
+        }
+        match following {
+            Some(&"{") if is_extern => usage.unsafe_extern_blocks += 1,
+            Some(&"{") => usage.unsafe_blocks += 1,
+            Some(&"fn") => usage.unsafe_fns += 1,
+            Some(&"impl") => usage.unsafe_impls += 1,
+            _ => {}
+        }
+    }
+    if usage != UnsafeUsage::default() {
+        usage.files_with_unsafe = 1;
+    }
+    usage
+}
+
+// ============ Crate Attributes ============
+
+/// A crate-level inner attribute such as `#![forbid(unsafe_code)]`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrateAttribute {
+    /// Attribute path, e.g. `forbid` or `cfg_attr`
+    pub name: String,
+    /// Top-level arguments with whitespace collapsed, e.g. `["unsafe_code"]`
+    pub args: Vec<String>,
+}
+
+impl CrateAttribute {
+    /// `#![forbid(unsafe_code)]` or `#![deny(unsafe_code)]`
+    pub fn bans_unsafe(&self) -> bool {
+        matches!(self.name.as_str(), "forbid" | "deny")
+            && self.args.iter().any(|a| a == "unsafe_code")
+    }
+
+    /// `#![no_std]`, including the conditional `#![cfg_attr(.., no_std)]` form
+    pub fn is_no_std(&self) -> bool {
+        self.name == "no_std"
+            || (self.name == "cfg_attr" && self.args.iter().skip(1).any(|a| a == "no_std"))
+    }
+}
+
+/// Inner attributes at the top of a crate root, in order. Attributes may span several
+/// lines and comments between them are skipped; scanning stops at the first item.
+pub fn scan_crate_attributes(content: &str) -> Vec<CrateAttribute> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut attributes = Vec::new();
+    let mut i = skip_trivia(&chars, 0);
+
+    while chars.get(i) == Some(&'#') && chars.get(i + 1) == Some(&'!') {
+        let open = skip_trivia(&chars, i + 2);
+        if chars.get(open) != Some(&'[') {
+            break;
+        }
+        let Some(close) = closing_bracket(&chars, open) else {
+            break;
+        };
+        let body: String = chars[open + 1..close].iter().collect();
+        attributes.push(parse_attribute_body(&body));
+        i = skip_trivia(&chars, close + 1);
+    }
+
+    attributes
+}
+
+/// Index of the first character at or after `i` that isn't whitespace or a comment
+fn skip_trivia(chars: &[char], mut i: usize) -> usize {
+    loop {
+        match (chars.get(i), chars.get(i + 1)) {
+            (Some(c), _) if c.is_whitespace() => i += 1,
+            (Some('/'), Some('/')) => {
+                while chars.get(i).is_some_and(|&c| c != '\n') {
+                    i += 1;
+                }
+            }
+            (Some('/'), Some('*')) => {
+                let mut depth = 0;
+                while i < chars.len() {
+                    match (chars[i], chars.get(i + 1)) {
+                        ('/', Some('*')) => {
+                            depth += 1;
+                            i += 1;
+                        }
+                        ('*', Some('/')) => {
+                            depth -= 1;
+                            i += 1;
+                            if depth == 0 {
+                                i += 1;
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                    i += 1;
+                }
+            }
+            _ => return i,
+        }
+    }
+}
+
+/// Index of the `]` closing the `[` at `open`, skipping over string literals
+fn closing_bracket(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < chars.len() {
+        match chars[i] {
+            '"' => {
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 && chars[i] == ']' {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn parse_attribute_body(body: &str) -> CrateAttribute {
+    let body = body.trim();
+    let name_end = body
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+        .unwrap_or(body.len());
+    let args = body[name_end..]
+        .trim()
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .map(split_top_level_args)
+        .unwrap_or_default();
+
+    CrateAttribute {
+        name: body[..name_end].to_string(),
+        args,
+    }
+}
+
+/// Split on commas outside nested parens and strings, dropping comments and
+/// collapsing whitespace
+fn split_top_level_args(inner: &str) -> Vec<String> {
+    let chars: Vec<char> = inner.chars().collect();
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if in_string {
+            in_string = escaped || c != '"';
+            escaped = !escaped && c == '\\';
+        } else {
+            match (c, chars.get(i + 1)) {
+                ('/', Some('/' | '*')) => {
+                    i = skip_trivia(&chars, i);
+                    current.push(' ');
+                    continue;
+                }
+                ('"', _) => in_string = true,
+                ('(' | '[' | '{', _) => depth += 1,
+                (')' | ']' | '}', _) => depth = depth.saturating_sub(1),
+                (',', _) if depth == 0 => {
+                    args.push(std::mem::take(&mut current));
+                    i += 1;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        current.push(c);
+        i += 1;
+    }
+    args.push(current);
+
+    args.iter()
+        .map(|arg| arg.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|arg| !arg.is_empty())
+        .collect()
+}
+
+// ============ Build Scripts ============
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildScriptDirectives {
+    /// `rerun-if-changed=...` / `rerun-if-env-changed=...` directives, in order
+    pub rerun_if: Vec<String>,
+    /// Whether the script emits `rustc-link-lib` / `rustc-link-search`
+    pub links_native: bool,
+}
+
+/// Find the `cargo:` (or `cargo::`) directives a build script prints. Only string
+/// contents on code lines count, so commented-out directives are ignored.
+pub fn scan_build_script(content: &str) -> BuildScriptDirectives {
+    let mut classifier = RustLineClassifier::default();
+    let mut directives = BuildScriptDirectives::default();
+
+    for line in content.lines() {
+        if classifier.classify(line) != LineKind::Code {
+            continue;
+        }
+        let mut rest = line;
+        while let Some(start) = rest.find("cargo:") {
+            rest = &rest[start + "cargo:".len()..];
+            let directive = rest.strip_prefix(':').unwrap_or(rest);
+            let directive = &directive[..directive.find(['"', '\\']).unwrap_or(directive.len())];
+            let key = directive.split('=').next().unwrap_or("");
+            if key.starts_with("rerun-if-") {
+                if !directives.rerun_if.iter().any(|d| d == directive) {
+                    directives.rerun_if.push(directive.to_string());
+                }
+            } else if key.starts_with("rustc-link-") {
+                directives.links_native = true;
+            }
+        }
+    }
+
+    directives
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============ Line Classifier Tests ============
+
+    #[test]
+    fn test_count_source_lines_basic() {
+        let src = r#"//! Crate docs
+
+/// Adds one
+fn add_one(x: u32) -> u32 {
+    x + 1 // trailing comment
+}
+"#;
+        let stats = count_source_lines(src);
+        assert_eq!(stats.files, 1);
+        assert_eq!(stats.code, 3);
+        assert_eq!(stats.comments, 2);
+        assert_eq!(stats.blank, 1);
+    }
+
+    #[test]
+    fn test_count_source_lines_block_comments() {
+        let src = r#"/*
+ * Multi-line block
+
+ */
+fn main() {} /* inline */
+/* outer /* nested */ still comment */
+let x = 1;
+"#;
+        let stats = count_source_lines(src);
+        assert_eq!(stats.comments, 5);
+        assert_eq!(stats.code, 2);
+        assert_eq!(stats.blank, 0);
+    }
+
+    #[test]
+    fn test_count_source_lines_strings_containing_slashes() {
+        let src = r#"let url = "https://example.com";
+let s = "/* not a comment";
+let t = 1;
+let c = '"';
+let u = 2;
+"#;
+        let stats = count_source_lines(src);
+        assert_eq!(stats.code, 5);
+        assert_eq!(stats.comments, 0);
+    }
+
+    #[test]
+    fn test_count_source_lines_multiline_strings() {
+        let src = "let s = \"first\n// still inside string\n\nend\";\n// real comment\n";
+        let stats = count_source_lines(src);
+        assert_eq!(stats.code, 4);
+        assert_eq!(stats.comments, 1);
+        assert_eq!(stats.blank, 0);
+    }
+
+    #[test]
+    fn test_count_source_lines_raw_strings() {
+        let src = "let s = r#\"contains \"quotes\" and // slashes\n/* more */\"#;\nlet lt: &'static str = \"x\";\n";
+        let stats = count_source_lines(src);
+        assert_eq!(stats.code, 3);
+        assert_eq!(stats.comments, 0);
+    }
+
+    #[test]
+    fn test_loc_stats_add() {
+        let mut total = LocStats::default();
+        total.add(&count_source_lines("fn a() {}\n"));
+        total.add(&count_source_lines("// c\n\n"));
+        assert_eq!(
+            total,
+            LocStats {
+                files: 2,
+                code: 1,
+                comments: 1,
+                blank: 1,
+            }
+        );
+    }
+
+    // ============ Build Script Tests ============
+
+    const BUILD_RS: &str = r#"use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=native/lib.c");
+    println!("cargo::rerun-if-env-changed=LIBFOO_DIR");
+    // println!("cargo:rerun-if-changed=old.c");
+    /* println!("cargo:rustc-link-lib=old"); */
+    cc::Build::new().file("native/lib.c").compile("foo");
+    let dir = env::var("LIBFOO_DIR").unwrap_or_default();
+    println!("cargo:rustc-link-search=native={}", dir);
+    println!("cargo:rerun-if-changed=build.rs");
+}
+"#;
+
+    #[test]
+    fn test_scan_build_script() {
+        let directives = scan_build_script(BUILD_RS);
+        assert_eq!(
+            directives.rerun_if,
+            vec![
+                "rerun-if-changed=build.rs",
+                "rerun-if-changed=native/lib.c",
+                "rerun-if-env-changed=LIBFOO_DIR",
+            ]
+        );
+        assert!(directives.links_native);
+    }
+
+    #[test]
+    fn test_scan_build_script_without_directives() {
+        let directives = scan_build_script("fn main() {\n    // cargo:rustc-link-lib=z\n}\n");
+        assert_eq!(directives, BuildScriptDirectives::default());
+    }
+
+    // ============ Unsafe Usage Tests ============
+
+    #[test]
+    fn test_scan_unsafe_usage_in_code() {
+        let src = r#"
+pub unsafe fn raw(ptr: *const u8) -> u8 {
+    unsafe { *ptr }
+}
+
+unsafe extern "C" fn callback() {}
+
+struct Wrapper(*mut u8);
+unsafe impl Send for Wrapper {}
+
+fn read(ptr: *const u8) -> u8 {
+    let value = unsafe
+    {
+        *ptr
+    };
+    value
+}
+"#;
+        assert_eq!(
+            scan_unsafe_usage(src),
+            UnsafeUsage {
+                unsafe_blocks: 2,
+                unsafe_fns: 2,
+                unsafe_impls: 1,
+                unsafe_extern_blocks: 0,
+                files_with_unsafe: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_scan_unsafe_usage_extern_blocks() {
+        let src = r#"
+unsafe extern "C" {
+    pub safe fn abs(input: i32) -> i32;
+    pub unsafe fn strlen(s: *const u8) -> usize;
+}
+
+unsafe extern {
+    static ERRNO: i32;
+}
+"#;
+        assert_eq!(
+            scan_unsafe_usage(src),
+            UnsafeUsage {
+                unsafe_blocks: 0,
+                unsafe_fns: 1,
+                unsafe_impls: 0,
+                unsafe_extern_blocks: 2,
+                files_with_unsafe: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_scan_unsafe_usage_ignores_strings_and_comments() {
+        let src = r##"
+// unsafe { this is a comment }
+/* unsafe fn in a
+   block comment: unsafe impl */
+/// Never call `unsafe { }` here
+fn describe() -> &'static str {
+    let _raw = r#"unsafe { raw string }"#;
+    let _quote = '"';
+    "unsafe fn in a string"
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn exported() {}
+"##;
+        assert_eq!(scan_unsafe_usage(src), UnsafeUsage::default());
+    }
+
+    #[test]
+    fn test_scan_blanks_literals_and_comments() {
+        let mut classifier = RustLineClassifier::default();
+        let (kind, code) = classifier.scan(r#"let s = "unsafe"; // unsafe"#);
+        assert_eq!(kind, LineKind::Code);
+        assert!(!code.contains("unsafe"));
+        assert!(code.starts_with("let s ="));
+    }
+
+    // ============ Crate Attribute Tests ============
+
+    #[test]
+    fn test_scan_crate_attributes() {
+        let src = r#"//! Crate docs mentioning #![no_std]
+/* licence
+   header */
+#![forbid(unsafe_code)]
+#![no_std]
+#![warn(
+    missing_docs,
+    clippy::pedantic, // noisy but useful
+)]
+#![doc = "Brackets ] in a string"]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+mod inner {
+    #![allow(dead_code)]
+}
+"#;
+        let attributes = scan_crate_attributes(src);
+        let names: Vec<&str> = attributes.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["forbid", "no_std", "warn", "doc", "cfg_attr"]);
+
+        assert_eq!(attributes[0].args, vec!["unsafe_code"]);
+        assert!(attributes[1].args.is_empty());
+        assert_eq!(attributes[2].args, vec!["missing_docs", "clippy::pedantic"]);
+        assert!(attributes[3].args.is_empty());
+        assert_eq!(
+            attributes[4].args,
+            vec![r#"not(feature = "std")"#.to_string(), "no_std".to_string()]
+        );
+
+        assert!(attributes[0].bans_unsafe());
+        assert!(attributes[1].is_no_std());
+        assert!(attributes[4].is_no_std());
+        assert!(!attributes[2].bans_unsafe());
+    }
+
+    #[test]
+    fn test_scan_crate_attributes_none() {
+        assert!(scan_crate_attributes("fn main() {}\n").is_empty());
+        assert!(scan_crate_attributes("#![deny(unsafe_code").is_empty());
+        let deny = scan_crate_attributes("#![deny(warnings, unsafe_code)]\nfn main() {}\n");
+        assert!(deny[0].bans_unsafe());
+    }
+}
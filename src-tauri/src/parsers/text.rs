@@ -1,5 +1,8 @@
 //! Text parsing functions for command output
 
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
 /// Parse rustup toolchain list output and return installed toolchains with default/active info
 pub fn parse_rustup_toolchain_list(output: &str) -> (Vec<String>, Option<String>, Option<String>) {
     let mut installed_toolchains = Vec::new();
@@ -36,6 +39,377 @@ pub fn parse_rustc_version(version_output: &str) -> (Option<String>, bool) {
     (version, is_homebrew)
 }
 
+/// Extract the `host:` triple from `rustc -vV` output
+pub fn parse_rustc_host(verbose_version: &str) -> Option<String> {
+    verbose_version
+        .lines()
+        .find_map(|line| line.strip_prefix("host:"))
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+}
+
+/// Parse `rustup target list --installed` output, one target triple per line
+pub fn parse_installed_targets(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Parse `cargo <tool> --version` output (e.g. "cargo-audit 0.18.3") and return the version
+pub fn parse_tool_version(version_output: &str) -> Option<String> {
+    version_output
+        .lines()
+        .next()?
+        .split_whitespace()
+        .map(|token| {
+            token
+                .trim_start_matches('v')
+                .trim_end_matches([',', ';', ')'])
+        })
+        .find(|token| token.starts_with(|c: char| c.is_ascii_digit()) && token.contains('.'))
+        .map(String::from)
+}
+
+/// One block of proposed formatting changes, starting at `start_line` in the original file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DiffHunk {
+    pub start_line: u32,
+    /// Diff lines keeping their ` `, `-` or `+` prefix
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FileDiff {
+    pub path: String,
+    pub hunks: Vec<DiffHunk>,
+}
+
+/// Parse a rustfmt `Diff in <file> at line N:` / `Diff in <file>:N:` header
+fn parse_rustfmt_diff_header(line: &str) -> Option<(String, u32)> {
+    let rest = line
+        .strip_prefix("Diff in ")?
+        .trim_end()
+        .strip_suffix(':')?;
+    let (path, start) = rest
+        .rsplit_once(" at line ")
+        .or_else(|| rest.rsplit_once(':'))?;
+    Some((path.to_string(), start.trim().parse().ok()?))
+}
+
+/// Parse `cargo fmt -- --check` output into per-file diffs, grouping hunks by file
+/// in the order rustfmt reports them
+pub fn parse_rustfmt_diff(output: &str) -> Vec<FileDiff> {
+    let mut files: Vec<FileDiff> = Vec::new();
+
+    for line in output.lines() {
+        if let Some((path, start_line)) = parse_rustfmt_diff_header(line) {
+            let hunk = DiffHunk {
+                start_line,
+                lines: Vec::new(),
+            };
+            match files.iter_mut().find(|f| f.path == path) {
+                Some(file) => file.hunks.push(hunk),
+                None => files.push(FileDiff {
+                    path,
+                    hunks: vec![hunk],
+                }),
+            }
+        } else if let Some(hunk) = files.last_mut().and_then(|f| f.hunks.last_mut()) {
+            if line.starts_with([' ', '-', '+']) || line.is_empty() {
+                hunk.lines.push(line.to_string());
+            }
+        }
+    }
+
+    // rustfmt separates hunks with a blank line; drop it from the hunk itself
+    for hunk in files.iter_mut().flat_map(|f| f.hunks.iter_mut()) {
+        while hunk.lines.last().is_some_and(|l| l.is_empty()) {
+            hunk.lines.pop();
+        }
+    }
+
+    files
+}
+
+/// A breaking change reported by cargo-semver-checks
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SemverIssue {
+    /// Lint id, e.g. "function_missing"
+    pub kind: String,
+    pub message: String,
+    /// The offending item as reported, e.g. "function demo::foo, previously in file src/lib.rs:5"
+    pub location: String,
+}
+
+/// Parse the failure reports from `cargo semver-checks check-release` output. Each
+/// `--- failure <lint>: <message> ---` block yields one issue per "Failed in:" entry.
+pub fn parse_semver_checks_output(output: &str) -> Vec<SemverIssue> {
+    let mut issues = Vec::new();
+    let mut current: Option<(String, String)> = None;
+    let mut in_locations = false;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed
+            .strip_prefix("--- ")
+            .and_then(|h| h.strip_suffix(" ---"))
+        {
+            in_locations = false;
+            current = header.strip_prefix("failure ").and_then(|rest| {
+                let (kind, message) = rest.split_once(':')?;
+                Some((kind.trim().to_string(), message.trim().to_string()))
+            });
+            continue;
+        }
+
+        let Some((kind, message)) = &current else {
+            continue;
+        };
+        if trimmed == "Failed in:" {
+            in_locations = true;
+        } else if in_locations {
+            // The location list ends at the first blank or unindented line
+            if trimmed.is_empty() || !line.starts_with(char::is_whitespace) {
+                in_locations = false;
+                current = None;
+            } else {
+                issues.push(SemverIssue {
+                    kind: kind.clone(),
+                    message: message.clone(),
+                    location: trimmed.to_string(),
+                });
+            }
+        }
+    }
+
+    issues
+}
+
+// ============ Libtest Output ============
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestTotals {
+    pub passed: u32,
+    pub failed: u32,
+    pub ignored: u32,
+}
+
+/// Sum the `test result: ... N passed; N failed; N ignored; ...` lines `cargo test`
+/// prints for each test binary (unit, integration and doc tests)
+pub fn parse_libtest_totals(output: &str) -> TestTotals {
+    let mut totals = TestTotals::default();
+    for line in output.lines() {
+        let Some(rest) = line.trim().strip_prefix("test result: ") else {
+            continue;
+        };
+        let counts = rest.split_once(". ").map_or(rest, |(_, counts)| counts);
+        for part in counts.split(';') {
+            let mut words = part.split_whitespace();
+            let (Some(count), Some(label)) = (words.next(), words.next()) else {
+                continue;
+            };
+            let Ok(count) = count.parse::<u32>() else {
+                continue;
+            };
+            match label {
+                "passed" => totals.passed += count,
+                "failed" => totals.failed += count,
+                "ignored" => totals.ignored += count,
+                _ => {}
+            }
+        }
+    }
+    totals
+}
+
+// ============ Git Log ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CommitInfo {
+    /// Abbreviated commit hash
+    pub hash: String,
+    pub subject: String,
+}
+
+/// Parse `git log --oneline --no-decorate` output into commits, newest first
+pub fn parse_git_log_oneline(output: &str) -> Vec<CommitInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() {
+                return None;
+            }
+            let (hash, subject) = line.split_once(' ').unwrap_or((line, ""));
+            Some(CommitInfo {
+                hash: hash.to_string(),
+                subject: subject.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+// ============ Changelog ============
+
+/// One release section of a Keep a Changelog style CHANGELOG.md
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ChangelogEntry {
+    /// Version without brackets or a leading "v", or "Unreleased"
+    pub version: String,
+    pub date: Option<String>,
+    /// Items keyed by subsection ("Added", "Fixed", ...). Items listed before any
+    /// subsection go under "Changes".
+    pub sections: HashMap<String, Vec<String>>,
+}
+
+const UNSECTIONED: &str = "Changes";
+
+fn is_iso_date(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+/// Parse a release heading like `[1.2.3] - 2024-01-01`, `[1.2.3](link) - 2024-01-01`,
+/// `v1.2.3 (2024-01-01)` or `[Unreleased]`. Other headings return None.
+fn parse_release_heading(heading: &str) -> Option<(String, Option<String>)> {
+    let heading = heading.trim();
+    let (version, rest) = match heading.strip_prefix('[') {
+        Some(bracketed) => bracketed.split_once(']')?,
+        None => heading
+            .split_once(char::is_whitespace)
+            .unwrap_or((heading, "")),
+    };
+    let version = version.trim();
+    let version = if version.eq_ignore_ascii_case("unreleased") {
+        "Unreleased"
+    } else {
+        let bare = version.strip_prefix('v').unwrap_or(version);
+        if !bare.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+        bare
+    };
+
+    let date = rest
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_ascii_alphanumeric()))
+        .find(|word| is_iso_date(word))
+        .map(String::from);
+    Some((version.to_string(), date))
+}
+
+/// Parse a Keep a Changelog style document into its release entries, in file order.
+/// `## <version>` starts an entry, `### <name>` a subsection, and `-`/`*` bullets are
+/// items; indented non-bullet lines continue the previous item.
+pub fn parse_changelog_markdown(markdown: &str) -> Vec<ChangelogEntry> {
+    let mut entries: Vec<ChangelogEntry> = Vec::new();
+    // Whether the most recent `##` heading was a release we're collecting into
+    let mut in_release = false;
+    let mut section = UNSECTIONED.to_string();
+    let mut last_item: Option<(String, usize)> = None;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some(heading) = trimmed.strip_prefix("## ") {
+            in_release = false;
+            last_item = None;
+            section = UNSECTIONED.to_string();
+            if let Some((version, date)) = parse_release_heading(heading) {
+                in_release = true;
+                entries.push(ChangelogEntry {
+                    version,
+                    date,
+                    sections: HashMap::new(),
+                });
+            }
+            continue;
+        }
+        if trimmed.starts_with("# ") {
+            in_release = false;
+            continue;
+        }
+        let Some(entry) = entries.last_mut().filter(|_| in_release) else {
+            continue;
+        };
+
+        if let Some(heading) = trimmed.strip_prefix("### ") {
+            section = heading.trim().to_string();
+            last_item = None;
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            let items = entry.sections.entry(section.clone()).or_default();
+            items.push(item.trim().to_string());
+            last_item = Some((section.clone(), items.len() - 1));
+        } else if trimmed.is_empty() {
+            last_item = None;
+        } else if line.starts_with(char::is_whitespace) {
+            if let Some((name, index)) = &last_item {
+                if let Some(item) = entry.sections.get_mut(name).and_then(|i| i.get_mut(*index)) {
+                    item.push(' ');
+                    item.push_str(trimmed);
+                }
+            }
+        }
+    }
+
+    entries
+}
+
+// ============ License Files ============
+
+/// Phrases from the heading of common license texts, most specific first so e.g.
+/// the LGPL isn't taken for the GPL
+const LICENSE_SIGNATURES: &[(&[&str], &str)] = &[
+    (&["apache license", "version 2.0"], "Apache-2.0"),
+    (&["mit license"], "MIT"),
+    (
+        &["gnu affero general public license", "version 3"],
+        "AGPL-3.0",
+    ),
+    (
+        &["gnu lesser general public license", "version 3"],
+        "LGPL-3.0",
+    ),
+    (
+        &["gnu lesser general public license", "version 2.1"],
+        "LGPL-2.1",
+    ),
+    (&["gnu general public license", "version 3"], "GPL-3.0"),
+    (&["gnu general public license", "version 2"], "GPL-2.0"),
+    (&["mozilla public license", "2.0"], "MPL-2.0"),
+    (&["bsd 3-clause"], "BSD-3-Clause"),
+    (&["bsd 2-clause"], "BSD-2-Clause"),
+    (&["isc license"], "ISC"),
+    (&["boost software license"], "BSL-1.0"),
+    (&["free and unencumbered software"], "Unlicense"),
+];
+
+/// Guess the SPDX id of a license file from its first few lines. A heuristic: texts
+/// without a recognizable heading (e.g. a bare MIT body) return None.
+pub fn sniff_license(text: &str) -> Option<&'static str> {
+    let heading = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .take(3)
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    LICENSE_SIGNATURES
+        .iter()
+        .find(|(phrases, _)| phrases.iter().all(|phrase| heading.contains(phrase)))
+        .map(|(_, spdx)| *spdx)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +496,381 @@ mod tests {
         assert!(version.is_none());
         assert!(!is_homebrew);
     }
+
+    // ============ Target Triple Parser Tests ============
+
+    #[test]
+    fn test_parse_rustc_host() {
+        let output = "rustc 1.82.0 (f6e511eec 2024-10-15)
+binary: rustc
+commit-hash: f6e511eec7342f59a25f7c0534f1dbea00d01b14
+commit-date: 2024-10-15
+host: aarch64-apple-darwin
+release: 1.82.0
+LLVM version: 19.1.1
+";
+        assert_eq!(
+            parse_rustc_host(output).as_deref(),
+            Some("aarch64-apple-darwin")
+        );
+        assert!(parse_rustc_host("rustc 1.82.0 (f6e511eec 2024-10-15)").is_none());
+    }
+
+    #[test]
+    fn test_parse_installed_targets() {
+        let output = "aarch64-apple-darwin\nwasm32-unknown-unknown\nx86_64-unknown-linux-gnu\n\n";
+        assert_eq!(
+            parse_installed_targets(output),
+            vec![
+                "aarch64-apple-darwin",
+                "wasm32-unknown-unknown",
+                "x86_64-unknown-linux-gnu"
+            ]
+        );
+        assert!(parse_installed_targets("").is_empty());
+    }
+
+    // ============ Tool Version Parser Tests ============
+
+    #[test]
+    fn test_parse_tool_version_plain() {
+        assert_eq!(
+            parse_tool_version("cargo-audit 0.18.3\n"),
+            Some("0.18.3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_tool_version_prefixed() {
+        assert_eq!(
+            parse_tool_version("cargo-outdated v0.15.0"),
+            Some("0.15.0".to_string())
+        );
+        assert_eq!(
+            parse_tool_version("cargo-tarpaulin version: 0.27.3"),
+            Some("0.27.3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_tool_version_with_build_info() {
+        let output = "cargo-nextest 0.9.67 (c6ec4a1d0 2024-01-09)\nrelease: 0.9.67";
+        assert_eq!(parse_tool_version(output), Some("0.9.67".to_string()));
+    }
+
+    #[test]
+    fn test_parse_tool_version_missing() {
+        assert!(parse_tool_version("").is_none());
+        assert!(parse_tool_version("error: no such command: `foo`").is_none());
+    }
+
+    // ============ Rustfmt Diff Parser Tests ============
+
+    const RUSTFMT_DIFF: &str = "Diff in /work/demo/src/main.rs:1:
+-use std::collections::{HashMap,HashSet};
++use std::collections::{HashMap, HashSet};
+
+ fn main() {
+Diff in /work/demo/src/main.rs:8:
+ }
+
+-fn helper( x:i32 )->i32{ x+1 }
++fn helper(x: i32) -> i32 {
++    x + 1
++}
+
+Diff in /work/demo/src/lib.rs:3:
+ pub mod parsers;
+-pub   mod commands;
++pub mod commands;
+
+";
+
+    #[test]
+    fn test_parse_rustfmt_diff_groups_hunks_by_file() {
+        let files = parse_rustfmt_diff(RUSTFMT_DIFF);
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "/work/demo/src/main.rs");
+        assert_eq!(files[0].hunks.len(), 2);
+        assert_eq!(files[0].hunks[0].start_line, 1);
+        assert_eq!(
+            files[0].hunks[0].lines[0],
+            "-use std::collections::{HashMap,HashSet};"
+        );
+        assert_eq!(files[0].hunks[1].start_line, 8);
+        assert_eq!(files[0].hunks[1].lines.len(), 6);
+        assert_eq!(files[0].hunks[1].lines.last().unwrap(), "+}");
+
+        assert_eq!(files[1].path, "/work/demo/src/lib.rs");
+        assert_eq!(
+            files[1].hunks[0].lines,
+            vec![
+                " pub mod parsers;",
+                "-pub   mod commands;",
+                "+pub mod commands;"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rustfmt_diff_old_header_format() {
+        let output = "Diff in /work/demo/src/main.rs at line 2:\n-fn a(){}\n+fn a() {}\n";
+        let files = parse_rustfmt_diff(output);
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "/work/demo/src/main.rs");
+        assert_eq!(files[0].hunks[0].start_line, 2);
+        assert_eq!(files[0].hunks[0].lines, vec!["-fn a(){}", "+fn a() {}"]);
+    }
+
+    #[test]
+    fn test_parse_rustfmt_diff_no_changes() {
+        assert!(parse_rustfmt_diff("").is_empty());
+        assert!(parse_rustfmt_diff("warning: some rustfmt notice\n").is_empty());
+    }
+
+    // ============ Semver Checks Parser Tests ============
+
+    const SEMVER_CHECKS_OUTPUT: &str = "     Parsing demo v0.2.0 (current)
+      Parsed [   1.021s] (current)
+     Parsing demo v0.1.0 (baseline, cached)
+      Parsed [   0.112s] (baseline)
+    Checking demo v0.1.0 -> v0.2.0 (minor change)
+     Checked [   0.031s] 84 checks: 82 pass, 2 fail, 0 warn, 0 skip
+
+--- failure function_missing: pub fn removed or renamed ---
+
+Description:
+A publicly-visible function cannot be imported by its prior path. A `pub use` may have been removed, or the function itself may have been renamed or removed entirely.
+        ref: https://doc.rust-lang.org/cargo/reference/semver.html#item-remove
+       impl: https://github.com/obi1kenobi/cargo-semver-checks/tree/v0.36.0/src/lints/function_missing.ron
+
+Failed in:
+  function demo::parse, previously in file /work/demo/src/lib.rs:12
+  function demo::render, previously in file /work/demo/src/lib.rs:30
+
+--- failure struct_pub_field_missing: pub struct's pub field removed or renamed ---
+
+Description:
+A publicly-visible struct has at least one public field that is no longer available under its prior name.
+        ref: https://doc.rust-lang.org/cargo/reference/semver.html#item-remove
+       impl: https://github.com/obi1kenobi/cargo-semver-checks/tree/v0.36.0/src/lints/struct_pub_field_missing.ron
+
+Failed in:
+  field width of struct Config, previously in file /work/demo/src/config.rs:4
+
+     Summary semver requires new major version: 2 major and 0 minor checks failed
+    Finished [   1.204s] demo
+";
+
+    #[test]
+    fn test_parse_semver_checks_output() {
+        let issues = parse_semver_checks_output(SEMVER_CHECKS_OUTPUT);
+
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].kind, "function_missing");
+        assert_eq!(issues[0].message, "pub fn removed or renamed");
+        assert_eq!(
+            issues[0].location,
+            "function demo::parse, previously in file /work/demo/src/lib.rs:12"
+        );
+        assert_eq!(issues[1].kind, "function_missing");
+        assert_eq!(
+            issues[1].location,
+            "function demo::render, previously in file /work/demo/src/lib.rs:30"
+        );
+        assert_eq!(
+            issues[2],
+            SemverIssue {
+                kind: "struct_pub_field_missing".to_string(),
+                message: "pub struct's pub field removed or renamed".to_string(),
+                location:
+                    "field width of struct Config, previously in file /work/demo/src/config.rs:4"
+                        .to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_semver_checks_output_no_issues() {
+        let output = "    Checking demo v0.1.0 -> v0.1.1 (patch change)
+     Checked [   0.030s] 84 checks: 84 pass, 0 skip
+     Summary no semver update required
+";
+        assert!(parse_semver_checks_output(output).is_empty());
+        assert!(parse_semver_checks_output("").is_empty());
+    }
+
+    // ============ Libtest Parser Tests ============
+
+    #[test]
+    fn test_parse_libtest_totals() {
+        let output = "\
+running 3 tests
+test a ... ok
+test b ... FAILED
+test c ... ignored
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.01s
+
+running 2 tests
+test result: ok. 2 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+
+   Doc-tests demo
+
+running 1 test
+test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.20s
+";
+        assert_eq!(
+            parse_libtest_totals(output),
+            TestTotals {
+                passed: 4,
+                failed: 1,
+                ignored: 1,
+            }
+        );
+        assert_eq!(
+            parse_libtest_totals("error: could not compile"),
+            TestTotals::default()
+        );
+    }
+
+    // ============ Git Log Parser Tests ============
+
+    #[test]
+    fn test_parse_git_log_oneline_range() {
+        // Output of `git log v1.2.0..HEAD --oneline --no-decorate`
+        let output = "\
+9fceb02 Add release badge
+3a1b2c4 Fix: handle  empty workspaces
+e83c516 wip
+";
+        let commits = parse_git_log_oneline(output);
+        assert_eq!(commits.len(), 3);
+        assert_eq!(
+            commits[0],
+            CommitInfo {
+                hash: "9fceb02".to_string(),
+                subject: "Add release badge".to_string(),
+            }
+        );
+        assert_eq!(commits[1].subject, "Fix: handle  empty workspaces");
+        assert_eq!(commits[2].hash, "e83c516");
+    }
+
+    #[test]
+    fn test_parse_git_log_oneline_empty() {
+        assert!(parse_git_log_oneline("").is_empty());
+        assert!(parse_git_log_oneline("\n\n").is_empty());
+        assert_eq!(parse_git_log_oneline("abc1234\n")[0].subject, "");
+    }
+
+    // ============ Changelog Parser Tests ============
+
+    const CHANGELOG: &str = "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [Unreleased]
+
+### Added
+- Dark mode
+
+## [1.2.0] - 2024-03-10
+
+### Added
+- `scan` command for
+  multiple roots
+- JSON output
+
+### Fixed
+* Crash on empty workspaces
+
+## [1.1.0](https://example.com/compare/v1.0.0...v1.1.0) - 2024-01-01
+
+- Faster startup
+
+## v1.0.0 (2023-12-01)
+
+### Removed
+- Legacy config format
+
+[Unreleased]: https://example.com/compare/v1.2.0...HEAD
+[1.2.0]: https://example.com/compare/v1.1.0...v1.2.0
+";
+
+    #[test]
+    fn test_parse_changelog_markdown() {
+        let entries = parse_changelog_markdown(CHANGELOG);
+        let versions: Vec<&str> = entries.iter().map(|e| e.version.as_str()).collect();
+        assert_eq!(versions, vec!["Unreleased", "1.2.0", "1.1.0", "1.0.0"]);
+
+        assert_eq!(entries[0].date, None);
+        assert_eq!(entries[0].sections["Added"], vec!["Dark mode"]);
+
+        let release = &entries[1];
+        assert_eq!(release.date.as_deref(), Some("2024-03-10"));
+        assert_eq!(
+            release.sections["Added"],
+            vec!["`scan` command for multiple roots", "JSON output"]
+        );
+        assert_eq!(release.sections["Fixed"], vec!["Crash on empty workspaces"]);
+        assert_eq!(release.sections.len(), 2);
+
+        assert_eq!(entries[2].date.as_deref(), Some("2024-01-01"));
+        assert_eq!(entries[2].sections["Changes"], vec!["Faster startup"]);
+
+        // Link references after the last release aren't items
+        assert_eq!(entries[3].date.as_deref(), Some("2023-12-01"));
+        assert_eq!(entries[3].sections["Removed"], vec!["Legacy config format"]);
+        assert_eq!(entries[3].sections.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_changelog_markdown_skips_non_release_headings() {
+        let markdown = "## Notes\n\n- not a release\n\n## 0.1.0\n\n- First\n";
+        let entries = parse_changelog_markdown(markdown);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].version, "0.1.0");
+        assert_eq!(entries[0].date, None);
+        assert_eq!(entries[0].sections["Changes"], vec!["First"]);
+
+        assert!(parse_changelog_markdown("").is_empty());
+        assert!(parse_changelog_markdown("# Changelog\n\nNothing yet.\n").is_empty());
+    }
+
+    // ============ License File Tests ============
+
+    #[test]
+    fn test_sniff_license() {
+        assert_eq!(
+            sniff_license("MIT License\n\nCopyright (c) 2024 Someone\n"),
+            Some("MIT")
+        );
+        assert_eq!(sniff_license("The MIT License (MIT)\n"), Some("MIT"));
+        assert_eq!(
+            sniff_license("\n                                 Apache License\n                           Version 2.0, January 2004\n"),
+            Some("Apache-2.0")
+        );
+        assert_eq!(
+            sniff_license("GNU LESSER GENERAL PUBLIC LICENSE\nVersion 3, 29 June 2007\n"),
+            Some("LGPL-3.0")
+        );
+        assert_eq!(
+            sniff_license("GNU GENERAL PUBLIC LICENSE\nVersion 2, June 1991\n"),
+            Some("GPL-2.0")
+        );
+        assert_eq!(
+            sniff_license(
+                "This is free and unencumbered software released into the public domain.\n"
+            ),
+            Some("Unlicense")
+        );
+        assert_eq!(
+            sniff_license("Permission is hereby granted, free of charge, to any person\n"),
+            None
+        );
+        assert_eq!(sniff_license(""), None);
+    }
 }
@@ -15,14 +15,19 @@ pub struct CargoFeature {
 pub struct CargoFeatures {
     pub features: Vec<CargoFeature>,
     pub default_features: Vec<String>,
+    /// A `default` feature is declared, even if it's an empty list
+    #[serde(default)]
+    pub has_default: bool,
 }
 
 /// Parse Cargo.toml features table and return structured features info
 pub fn parse_cargo_features_toml(table: &toml::Table) -> CargoFeatures {
     let mut features = Vec::new();
     let mut default_features = Vec::new();
+    let mut has_default = false;
 
     if let Some(features_table) = table.get("features").and_then(|f| f.as_table()) {
+        has_default = features_table.contains_key("default");
         // Get default features first
         if let Some(default) = features_table.get("default").and_then(|d| d.as_array()) {
             default_features = default
@@ -58,6 +63,7 @@ pub fn parse_cargo_features_toml(table: &toml::Table) -> CargoFeatures {
     CargoFeatures {
         features,
         default_features,
+        has_default,
     }
 }
 
@@ -89,6 +95,536 @@ pub fn parse_msrv_toml(table: &toml::Table) -> MsrvInfo {
     }
 }
 
+// ============ Resolver ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ResolverInfo {
+    /// Effective feature resolver version ("1", "2" or "3")
+    pub resolver: String,
+    /// True when no `resolver` key is set and the version comes from the edition
+    pub inferred: bool,
+}
+
+/// Resolver cargo uses when none is set: editions 2021+ imply "2" (2024 implies "3"),
+/// anything older or unset falls back to "1"
+pub fn infer_resolver(edition: Option<&str>) -> &'static str {
+    match edition {
+        Some("2024") => "3",
+        Some("2021") => "2",
+        _ => "1",
+    }
+}
+
+/// Read `workspace.resolver` or `package.resolver`, falling back to the edition default.
+/// Virtual workspaces have no package edition, so they default to "1".
+pub fn parse_resolver_toml(table: &toml::Table) -> ResolverInfo {
+    let section_str = |section: &str, key: &str| {
+        table
+            .get(section)
+            .and_then(|s| s.get(key))
+            .and_then(|v| v.as_str())
+    };
+
+    match section_str("workspace", "resolver").or_else(|| section_str("package", "resolver")) {
+        Some(resolver) => ResolverInfo {
+            resolver: resolver.to_string(),
+            inferred: false,
+        },
+        None => ResolverInfo {
+            resolver: infer_resolver(section_str("package", "edition")).to_string(),
+            inferred: true,
+        },
+    }
+}
+
+// ============ Cargo Registries ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CargoRegistry {
+    pub name: String,
+    pub index: Option<String>,
+}
+
+/// Alternative registry settings from a `.cargo/config.toml`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct CargoRegistryConfig {
+    pub registries: Vec<CargoRegistry>,
+    /// Source that crates.io is redirected to via `[source.crates-io] replace-with`
+    pub crates_io_replacement: Option<String>,
+}
+
+/// Parse `[registries.*]` and the crates.io source replacement from a cargo config table
+pub fn parse_cargo_registry_config(table: &toml::Table) -> CargoRegistryConfig {
+    let mut registries: Vec<CargoRegistry> = table
+        .get("registries")
+        .and_then(|r| r.as_table())
+        .map(|registries| {
+            registries
+                .iter()
+                .map(|(name, registry)| CargoRegistry {
+                    name: name.clone(),
+                    index: registry
+                        .get("index")
+                        .and_then(|i| i.as_str())
+                        .map(String::from),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    registries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    CargoRegistryConfig {
+        registries,
+        crates_io_replacement: table
+            .get("source")
+            .and_then(|s| s.get("crates-io"))
+            .and_then(|c| c.get("replace-with"))
+            .and_then(|r| r.as_str())
+            .map(String::from),
+    }
+}
+
+/// Directory that crates.io is replaced with, as set up by `cargo vendor`:
+/// `[source.crates-io] replace-with` naming a source that has a `directory` key
+pub fn parse_vendor_directory(table: &toml::Table) -> Option<String> {
+    let sources = table.get("source")?;
+    let replacement = sources.get("crates-io")?.get("replace-with")?.as_str()?;
+    sources
+        .get(replacement)?
+        .get("directory")?
+        .as_str()
+        .map(String::from)
+}
+
+// ============ Cargo Aliases ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CargoAlias {
+    pub alias: String,
+    /// What `cargo <alias>` runs, with array-form aliases joined by spaces
+    pub expansion: String,
+}
+
+/// Parse the `[alias]` table of a cargo config. Aliases may be a string or a list of args.
+pub fn parse_cargo_aliases(table: &toml::Table) -> Vec<CargoAlias> {
+    let mut aliases: Vec<CargoAlias> = table
+        .get("alias")
+        .and_then(|a| a.as_table())
+        .into_iter()
+        .flatten()
+        .filter_map(|(alias, value)| {
+            let expansion = match value {
+                toml::Value::String(s) => s.trim().to_string(),
+                toml::Value::Array(args) => args
+                    .iter()
+                    .map(|a| a.as_str())
+                    .collect::<Option<Vec<&str>>>()?
+                    .join(" "),
+                _ => return None,
+            };
+            Some(CargoAlias {
+                alias: alias.clone(),
+                expansion,
+            })
+        })
+        .collect();
+    aliases.sort_by(|a, b| a.alias.cmp(&b.alias));
+    aliases
+}
+
+// ============ Build Targets ============
+
+/// Targets from `[build] target` in a cargo config, which may be a string or a list
+pub fn parse_build_targets(table: &toml::Table) -> Vec<String> {
+    match table.get("build").and_then(|b| b.get("target")) {
+        Some(toml::Value::String(target)) => vec![target.clone()],
+        Some(toml::Value::Array(targets)) => targets
+            .iter()
+            .filter_map(|t| t.as_str().map(String::from))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Targets from `[toolchain] targets` in a `rust-toolchain.toml`
+pub fn parse_toolchain_targets(table: &toml::Table) -> Vec<String> {
+    table
+        .get("toolchain")
+        .and_then(|t| t.get("targets"))
+        .and_then(|t| t.as_array())
+        .map(|targets| {
+            targets
+                .iter()
+                .filter_map(|t| t.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// ============ Project Settings ============
+
+/// Per-project defaults read from a `.rust-helper.toml` in the project root
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct ProjectSettings {
+    pub default_features: Vec<String>,
+    pub default_toolchain: Option<String>,
+    pub test_args: Vec<String>,
+    pub build_args: Vec<String>,
+}
+
+/// Parse the contents of a `.rust-helper.toml` override file
+pub fn parse_project_settings(content: &str) -> Result<ProjectSettings, String> {
+    toml::from_str(content).map_err(|e| format!("Invalid .rust-helper.toml: {}", e))
+}
+
+// ============ Lint Configs ============
+
+/// Raw key/value contents of a project's rustfmt and clippy config files
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct LintConfigs {
+    pub rustfmt: Option<toml::Table>,
+    pub clippy: Option<toml::Table>,
+}
+
+/// Parse the contents of the rustfmt/clippy config files. A missing file is passed
+/// as None and stays None in the result.
+pub fn parse_lint_configs(
+    rustfmt: Option<&str>,
+    clippy: Option<&str>,
+) -> Result<LintConfigs, String> {
+    let parse = |content: &str, file: &str| {
+        content
+            .parse::<toml::Table>()
+            .map_err(|e| format!("Invalid {}: {}", file, e))
+    };
+
+    Ok(LintConfigs {
+        rustfmt: rustfmt.map(|c| parse(c, "rustfmt.toml")).transpose()?,
+        clippy: clippy.map(|c| parse(c, "clippy.toml")).transpose()?,
+    })
+}
+
+// ============ cargo-deny Config ============
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct DenyLicenses {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct DenyBans {
+    /// Banned crates as `name` or `name@version-req`
+    pub deny: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct DenyAdvisories {
+    /// Advisory IDs that are allowed to fail the check
+    pub ignore: Vec<String>,
+}
+
+/// The policy a `deny.toml` enforces
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct DenyConfig {
+    pub licenses: DenyLicenses,
+    pub bans: DenyBans,
+    pub advisories: DenyAdvisories,
+}
+
+/// Parse `deny.toml`. List entries may be plain strings or tables; tables contribute
+/// their `name`/`crate`/`id` key (plus `version` for bans, if set).
+pub fn parse_deny_toml(content: &str) -> Result<DenyConfig, String> {
+    let table = content
+        .parse::<toml::Table>()
+        .map_err(|e| format!("Invalid deny.toml: {}", e))?;
+
+    let list = |section: &str, key: &str, id_keys: &[&str]| -> Vec<String> {
+        let entries = table
+            .get(section)
+            .and_then(|s| s.get(key))
+            .and_then(|v| v.as_array());
+        entries
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| match entry {
+                toml::Value::String(s) => Some(s.clone()),
+                toml::Value::Table(t) => {
+                    let id = id_keys.iter().find_map(|k| t.get(*k)?.as_str())?;
+                    Some(match t.get("version").and_then(|v| v.as_str()) {
+                        Some(version) => format!("{}@{}", id, version),
+                        None => id.to_string(),
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    };
+
+    Ok(DenyConfig {
+        licenses: DenyLicenses {
+            allow: list("licenses", "allow", &[]),
+            deny: list("licenses", "deny", &[]),
+        },
+        bans: DenyBans {
+            deny: list("bans", "deny", &["crate", "name"]),
+        },
+        advisories: DenyAdvisories {
+            ignore: list("advisories", "ignore", &["id"]),
+        },
+    })
+}
+
+// ============ Lints Table ============
+
+/// One entry from a `[lints.<group>]` table
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LintSetting {
+    /// Tool the lint belongs to: "rust", "clippy" or "rustdoc"
+    pub group: String,
+    pub name: String,
+    pub level: String,
+    pub priority: i64,
+}
+
+/// Flatten a `[lints]` or `[workspace.lints]` table. Accepts both `name = "level"`
+/// and `name = { level = "...", priority = N }`.
+fn parse_lint_groups(lints: &toml::Table) -> Vec<LintSetting> {
+    let mut settings = Vec::new();
+    for (group, entries) in lints {
+        let Some(entries) = entries.as_table() else {
+            continue;
+        };
+        for (name, value) in entries {
+            let (level, priority) = match value {
+                toml::Value::String(level) => (Some(level.as_str()), 0),
+                toml::Value::Table(t) => (
+                    t.get("level").and_then(|l| l.as_str()),
+                    t.get("priority").and_then(|p| p.as_integer()).unwrap_or(0),
+                ),
+                _ => (None, 0),
+            };
+            if let Some(level) = level {
+                settings.push(LintSetting {
+                    group: group.clone(),
+                    name: name.clone(),
+                    level: level.to_string(),
+                    priority,
+                });
+            }
+        }
+    }
+    settings
+}
+
+/// Whether the manifest opts into its workspace's lints with `[lints] workspace = true`
+pub fn lints_inherit_workspace(table: &toml::Table) -> bool {
+    table
+        .get("lints")
+        .and_then(|l| l.get("workspace"))
+        .and_then(|w| w.as_bool())
+        .unwrap_or(false)
+}
+
+/// Lints declared in the package's own `[lints]` table
+pub fn parse_lints_toml(table: &toml::Table) -> Vec<LintSetting> {
+    table
+        .get("lints")
+        .and_then(|l| l.as_table())
+        .map(parse_lint_groups)
+        .unwrap_or_default()
+}
+
+/// Lints declared in a workspace root's `[workspace.lints]` table
+pub fn parse_workspace_lints_toml(table: &toml::Table) -> Vec<LintSetting> {
+    table
+        .get("workspace")
+        .and_then(|w| w.get("lints"))
+        .and_then(|l| l.as_table())
+        .map(parse_lint_groups)
+        .unwrap_or_default()
+}
+
+// ============ Manifest Metadata ============
+
+/// `[package]` fields crates.io and `cargo publish` expect a published crate to have
+pub const RECOMMENDED_PACKAGE_FIELDS: &[&str] = &[
+    "description",
+    "license",
+    "repository",
+    "readme",
+    "keywords",
+    "categories",
+];
+
+/// Recommended package fields missing from a manifest, in `RECOMMENDED_PACKAGE_FIELDS`
+/// order. `license-file` stands in for `license`, and `field.workspace = true` counts
+/// as set. None for virtual manifests and `publish = false` packages.
+pub fn lint_cargo_toml(table: &toml::Table) -> Option<Vec<String>> {
+    let package = table.get("package")?.as_table()?;
+    if package.get("publish").and_then(|p| p.as_bool()) == Some(false) {
+        return None;
+    }
+
+    let is_set = |field: &str| match package.get(field) {
+        Some(toml::Value::String(s)) => !s.trim().is_empty(),
+        Some(toml::Value::Array(a)) => !a.is_empty(),
+        Some(_) => true,
+        None => false,
+    };
+    Some(
+        RECOMMENDED_PACKAGE_FIELDS
+            .iter()
+            .filter(|field| !(is_set(field) || (**field == "license" && is_set("license-file"))))
+            .map(|field| field.to_string())
+            .collect(),
+    )
+}
+
+// ============ Build Profiles ============
+
+/// Profiles cargo always defines, with their built-in parents
+const BUILTIN_PROFILES: &[(&str, Option<&str>)] = &[
+    ("dev", None),
+    ("release", None),
+    ("test", Some("dev")),
+    ("bench", Some("release")),
+];
+
+/// Guards against `inherits` cycles, which cargo rejects anyway
+const MAX_PROFILE_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PanicStrategy {
+    pub profile: String,
+    /// `unwind` or `abort`
+    pub strategy: String,
+    /// `explicit` when set on the profile itself, `inherited`, or `default`
+    pub source: String,
+}
+
+/// A `[profile.*]` table from Cargo.toml. Built-in profiles are always present.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BuildProfile {
+    pub name: String,
+    /// `inherits`, or the built-in parent (test -> dev, bench -> release)
+    pub inherits: Option<String>,
+    pub opt_level: Option<String>,
+    pub lto: Option<String>,
+    pub codegen_units: Option<i64>,
+    pub strip: Option<String>,
+    pub panic: PanicStrategy,
+}
+
+fn profile_parent(profiles: Option<&toml::Table>, name: &str) -> Option<String> {
+    let inherits = profiles
+        .and_then(|p| p.get(name))
+        .and_then(|p| p.get("inherits"))
+        .and_then(|i| i.as_str());
+    inherits.map(String::from).or_else(|| {
+        BUILTIN_PROFILES
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .and_then(|(_, parent)| parent.map(String::from))
+    })
+}
+
+/// The panic strategy a profile builds with, following `inherits`. Cargo ignores
+/// `panic` for the test and bench profiles, so they always unwind.
+pub fn resolve_panic_strategy(table: &toml::Table, profile: &str) -> PanicStrategy {
+    let profiles = table.get("profile").and_then(|p| p.as_table());
+    let resolved = |strategy: &str, source: &str| PanicStrategy {
+        profile: profile.to_string(),
+        strategy: strategy.to_string(),
+        source: source.to_string(),
+    };
+    if profile == "test" || profile == "bench" {
+        return resolved("unwind", "default");
+    }
+
+    let mut current = profile.to_string();
+    for _ in 0..MAX_PROFILE_DEPTH {
+        let panic = profiles
+            .and_then(|p| p.get(&current))
+            .and_then(|p| p.get("panic"))
+            .and_then(|p| p.as_str());
+        if let Some(strategy) = panic {
+            let source = if current == profile {
+                "explicit"
+            } else {
+                "inherited"
+            };
+            return resolved(strategy, source);
+        }
+        match profile_parent(profiles, &current) {
+            Some(parent) => current = parent,
+            None => break,
+        }
+    }
+    resolved("unwind", "default")
+}
+
+/// Built-in profiles followed by custom ones, by name
+pub fn parse_build_profiles(table: &toml::Table) -> Vec<BuildProfile> {
+    let profiles = table.get("profile").and_then(|p| p.as_table());
+    let mut names: Vec<String> = BUILTIN_PROFILES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+    for name in profiles.into_iter().flat_map(|p| p.keys()) {
+        if !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+
+    // Integers, booleans, and strings all show up here (`opt-level = "z"`, `lto = true`)
+    let setting = |name: &str, key: &str| match profiles?.get(name)?.get(key)? {
+        toml::Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    };
+    names
+        .into_iter()
+        .map(|name| BuildProfile {
+            inherits: profile_parent(profiles, &name),
+            opt_level: setting(&name, "opt-level"),
+            lto: setting(&name, "lto"),
+            codegen_units: profiles
+                .and_then(|p| p.get(&name))
+                .and_then(|p| p.get("codegen-units"))
+                .and_then(|c| c.as_integer()),
+            strip: setting(&name, "strip"),
+            panic: resolve_panic_strategy(table, &name),
+            name,
+        })
+        .collect()
+}
+
+// ============ Cargo.lock ============
+
+/// A `[[package]]` entry from Cargo.lock
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    /// Registry or git source; None for path and workspace packages
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CargoLockFile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+/// Every package pinned in a Cargo.lock
+pub fn parse_cargo_lock(content: &str) -> Result<Vec<LockedPackage>, String> {
+    toml::from_str::<CargoLockFile>(content)
+        .map(|lock| lock.package)
+        .map_err(|e| format!("Invalid Cargo.lock: {}", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,6 +647,7 @@ async = []
         let features = parse_cargo_features_toml(&table);
 
         assert_eq!(features.default_features, vec!["serde"]);
+        assert!(features.has_default);
         assert_eq!(features.features.len(), 3);
 
         // Features should be sorted alphabetically
@@ -148,6 +685,7 @@ async = []
         let features = parse_cargo_features_toml(&table);
 
         assert!(features.default_features.is_empty());
+        assert!(!features.has_default);
         assert_eq!(features.features.len(), 2);
         assert!(!features.features[0].is_default);
         assert!(!features.features[1].is_default);
@@ -169,6 +707,74 @@ minimal = []
         assert!(full_feature.dependencies.contains(&"tokio".to_string()));
     }
 
+    // ============ Resolver Parser Tests ============
+
+    #[test]
+    fn test_parse_resolver_toml_explicit() {
+        let toml_str = r#"
+[package]
+name = "test-crate"
+edition = "2018"
+resolver = "2"
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert_eq!(
+            parse_resolver_toml(&table),
+            ResolverInfo {
+                resolver: "2".to_string(),
+                inferred: false,
+            }
+        );
+
+        let workspace: toml::Table = "[workspace]\nmembers = [\"a\"]\nresolver = \"2\"\n"
+            .parse()
+            .unwrap();
+        assert_eq!(parse_resolver_toml(&workspace).resolver, "2");
+        assert!(!parse_resolver_toml(&workspace).inferred);
+    }
+
+    #[test]
+    fn test_parse_resolver_toml_inferred_from_edition_2021() {
+        let toml_str = r#"
+[package]
+name = "test-crate"
+edition = "2021"
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert_eq!(
+            parse_resolver_toml(&table),
+            ResolverInfo {
+                resolver: "2".to_string(),
+                inferred: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_resolver_toml_edition_2018_default() {
+        let toml_str = r#"
+[package]
+name = "test-crate"
+edition = "2018"
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert_eq!(
+            parse_resolver_toml(&table),
+            ResolverInfo {
+                resolver: "1".to_string(),
+                inferred: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_infer_resolver() {
+        assert_eq!(infer_resolver(Some("2024")), "3");
+        assert_eq!(infer_resolver(Some("2021")), "2");
+        assert_eq!(infer_resolver(Some("2015")), "1");
+        assert_eq!(infer_resolver(None), "1");
+    }
+
     // ============ MSRV Parser Tests ============
 
     #[test]
@@ -225,4 +831,541 @@ members = ["crate-a", "crate-b"]
         assert!(msrv.rust_version.is_none());
         assert!(msrv.edition.is_none());
     }
+
+    // ============ Project Settings Parser Tests ============
+
+    #[test]
+    fn test_parse_project_settings_full() {
+        let content = r#"
+default_features = ["full", "serde"]
+default_toolchain = "nightly"
+test_args = ["--workspace", "--", "--nocapture"]
+build_args = ["--locked"]
+"#;
+        let settings = parse_project_settings(content).unwrap();
+
+        assert_eq!(settings.default_features, vec!["full", "serde"]);
+        assert_eq!(settings.default_toolchain, Some("nightly".to_string()));
+        assert_eq!(settings.test_args, vec!["--workspace", "--", "--nocapture"]);
+        assert_eq!(settings.build_args, vec!["--locked"]);
+    }
+
+    #[test]
+    fn test_parse_project_settings_partial() {
+        let settings = parse_project_settings("default_toolchain = \"1.75\"").unwrap();
+
+        assert_eq!(settings.default_toolchain, Some("1.75".to_string()));
+        assert!(settings.default_features.is_empty());
+        assert!(settings.test_args.is_empty());
+        assert!(settings.build_args.is_empty());
+    }
+
+    #[test]
+    fn test_parse_project_settings_empty() {
+        assert_eq!(
+            parse_project_settings("").unwrap(),
+            ProjectSettings::default()
+        );
+    }
+
+    #[test]
+    fn test_parse_project_settings_invalid() {
+        assert!(parse_project_settings("default_features = \"full\"").is_err());
+        assert!(parse_project_settings("not toml [").is_err());
+    }
+
+    // ============ Lint Config Parser Tests ============
+
+    #[test]
+    fn test_parse_lint_configs_full() {
+        let rustfmt = r#"
+edition = "2021"
+max_width = 120
+imports_granularity = "Crate"
+use_small_heuristics = "Max"
+"#;
+        let clippy = r#"
+msrv = "1.70"
+cognitive-complexity-threshold = 30
+too-many-arguments-threshold = 10
+disallowed-names = ["foo", "bar"]
+"#;
+        let configs = parse_lint_configs(Some(rustfmt), Some(clippy)).unwrap();
+
+        let rustfmt = configs.rustfmt.unwrap();
+        assert_eq!(rustfmt["edition"].as_str(), Some("2021"));
+        assert_eq!(rustfmt["max_width"].as_integer(), Some(120));
+        assert_eq!(rustfmt.len(), 4);
+
+        let clippy = configs.clippy.unwrap();
+        assert_eq!(clippy["msrv"].as_str(), Some("1.70"));
+        assert_eq!(
+            clippy["cognitive-complexity-threshold"].as_integer(),
+            Some(30)
+        );
+        assert_eq!(clippy["disallowed-names"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_lint_configs_absent() {
+        assert_eq!(
+            parse_lint_configs(None, None).unwrap(),
+            LintConfigs::default()
+        );
+
+        let configs = parse_lint_configs(Some("max_width = 80"), None).unwrap();
+        assert!(configs.rustfmt.is_some());
+        assert!(configs.clippy.is_none());
+
+        // An empty file is present but has no settings
+        let configs = parse_lint_configs(None, Some("")).unwrap();
+        assert!(configs.clippy.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_lint_configs_invalid() {
+        let err = parse_lint_configs(Some("max_width = "), None).unwrap_err();
+        assert!(err.starts_with("Invalid rustfmt.toml"));
+
+        let err = parse_lint_configs(None, Some("msrv = [")).unwrap_err();
+        assert!(err.starts_with("Invalid clippy.toml"));
+    }
+
+    // ============ Cargo Registry Parser Tests ============
+
+    #[test]
+    fn test_parse_cargo_registry_config() {
+        let toml_str = r#"
+[registries.my-corp]
+index = "sparse+https://cargo.my-corp.example/index/"
+
+[registries.legacy]
+index = "https://git.my-corp.example/cargo-index.git"
+
+[source.crates-io]
+replace-with = "corp-mirror"
+
+[source.corp-mirror]
+registry = "sparse+https://mirror.my-corp.example/index/"
+
+[build]
+jobs = 4
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        let config = parse_cargo_registry_config(&table);
+
+        assert_eq!(config.registries.len(), 2);
+        assert_eq!(config.registries[0].name, "legacy");
+        assert_eq!(
+            config.registries[0].index.as_deref(),
+            Some("https://git.my-corp.example/cargo-index.git")
+        );
+        assert_eq!(config.registries[1].name, "my-corp");
+        assert_eq!(config.crates_io_replacement.as_deref(), Some("corp-mirror"));
+    }
+
+    #[test]
+    fn test_parse_cargo_registry_config_none() {
+        let table: toml::Table = "[build]\njobs = 4\n".parse().unwrap();
+        assert_eq!(
+            parse_cargo_registry_config(&table),
+            CargoRegistryConfig::default()
+        );
+
+        // A registry with only a token-provider setting still counts
+        let table: toml::Table = "[registries.internal]\ncredential-provider = \"cargo:token\"\n"
+            .parse()
+            .unwrap();
+        let config = parse_cargo_registry_config(&table);
+        assert_eq!(config.registries[0].name, "internal");
+        assert!(config.registries[0].index.is_none());
+    }
+
+    #[test]
+    fn test_parse_vendor_directory() {
+        let toml_str = r#"
+[source.crates-io]
+replace-with = "vendored-sources"
+
+[source.vendored-sources]
+directory = "vendor"
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert_eq!(parse_vendor_directory(&table).as_deref(), Some("vendor"));
+
+        // A registry mirror replaces crates.io without vendoring
+        let toml_str = r#"
+[source.crates-io]
+replace-with = "corp-mirror"
+
+[source.corp-mirror]
+registry = "sparse+https://mirror.my-corp.example/index/"
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert!(parse_vendor_directory(&table).is_none());
+
+        let table: toml::Table = "[build]\njobs = 4\n".parse().unwrap();
+        assert!(parse_vendor_directory(&table).is_none());
+    }
+
+    // ============ Cargo Alias Parser Tests ============
+
+    #[test]
+    fn test_parse_cargo_aliases() {
+        let table: toml::Table = r#"
+[alias]
+xtask = "run --package xtask --"
+b = "build"
+lint = ["clippy", "--all-targets", "--", "-D", "warnings"]
+bad = 1
+
+[build]
+jobs = 4
+"#
+        .parse()
+        .unwrap();
+        let aliases = parse_cargo_aliases(&table);
+        let found: Vec<(&str, &str)> = aliases
+            .iter()
+            .map(|a| (a.alias.as_str(), a.expansion.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("b", "build"),
+                ("lint", "clippy --all-targets -- -D warnings"),
+                ("xtask", "run --package xtask --"),
+            ]
+        );
+
+        assert!(parse_cargo_aliases(&toml::Table::new()).is_empty());
+    }
+
+    // ============ Build Target Parser Tests ============
+
+    #[test]
+    fn test_parse_build_targets() {
+        let table: toml::Table = "[build]\ntarget = \"wasm32-unknown-unknown\"\n"
+            .parse()
+            .unwrap();
+        assert_eq!(parse_build_targets(&table), vec!["wasm32-unknown-unknown"]);
+
+        let table: toml::Table =
+            "[build]\ntarget = [\"aarch64-apple-darwin\", \"x86_64-apple-darwin\"]\n"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            parse_build_targets(&table),
+            vec!["aarch64-apple-darwin", "x86_64-apple-darwin"]
+        );
+
+        let table: toml::Table = "[build]\njobs = 4\n".parse().unwrap();
+        assert!(parse_build_targets(&table).is_empty());
+    }
+
+    #[test]
+    fn test_parse_toolchain_targets() {
+        let table: toml::Table = r#"
+[toolchain]
+channel = "1.82.0"
+components = ["rustfmt", "clippy"]
+targets = ["wasm32-unknown-unknown", "thumbv7em-none-eabihf"]
+"#
+        .parse()
+        .unwrap();
+        assert_eq!(
+            parse_toolchain_targets(&table),
+            vec!["wasm32-unknown-unknown", "thumbv7em-none-eabihf"]
+        );
+
+        let table: toml::Table = "[toolchain]\nchannel = \"stable\"\n".parse().unwrap();
+        assert!(parse_toolchain_targets(&table).is_empty());
+    }
+
+    // ============ cargo-deny Config Parser Tests ============
+
+    #[test]
+    fn test_parse_deny_toml() {
+        let content = r#"
+[graph]
+targets = ["x86_64-unknown-linux-gnu"]
+
+[advisories]
+version = 2
+ignore = [
+    "RUSTSEC-2020-0071",
+    { id = "RUSTSEC-2023-0052", reason = "not reachable" },
+]
+
+[licenses]
+version = 2
+allow = ["MIT", "Apache-2.0", "Unicode-3.0"]
+confidence-threshold = 0.9
+
+[bans]
+multiple-versions = "warn"
+deny = [
+    "openssl-sys",
+    { crate = "git2", reason = "use gix" },
+    { name = "time", version = "<0.2" },
+]
+"#;
+        let config = parse_deny_toml(content).unwrap();
+        assert_eq!(
+            config.licenses.allow,
+            vec!["MIT", "Apache-2.0", "Unicode-3.0"]
+        );
+        assert!(config.licenses.deny.is_empty());
+        assert_eq!(config.bans.deny, vec!["openssl-sys", "git2", "time@<0.2"]);
+        assert_eq!(
+            config.advisories.ignore,
+            vec!["RUSTSEC-2020-0071", "RUSTSEC-2023-0052"]
+        );
+    }
+
+    #[test]
+    fn test_parse_deny_toml_sparse() {
+        let config = parse_deny_toml("[licenses]\ndeny = [\"GPL-3.0\"]\n").unwrap();
+        assert_eq!(config.licenses.deny, vec!["GPL-3.0"]);
+        assert!(config.bans.deny.is_empty());
+        assert_eq!(parse_deny_toml("").unwrap(), DenyConfig::default());
+        assert!(parse_deny_toml(
+            "[licenses
+"
+        )
+        .is_err());
+    }
+
+    // ============ Lints Table Parser Tests ============
+
+    fn lint(group: &str, name: &str, level: &str, priority: i64) -> LintSetting {
+        LintSetting {
+            group: group.to_string(),
+            name: name.to_string(),
+            level: level.to_string(),
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_parse_lints_toml_short_and_table_forms() {
+        let toml_str = r#"
+[package]
+name = "test-crate"
+
+[lints.rust]
+unsafe_code = "forbid"
+unused = { level = "deny", priority = -1 }
+
+[lints.clippy]
+pedantic = { level = "warn", priority = -1 }
+module_name_repetitions = "allow"
+
+[lints.rustdoc]
+broken_intra_doc_links = "deny"
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert!(!lints_inherit_workspace(&table));
+        assert_eq!(
+            parse_lints_toml(&table),
+            vec![
+                lint("clippy", "module_name_repetitions", "allow", 0),
+                lint("clippy", "pedantic", "warn", -1),
+                lint("rust", "unsafe_code", "forbid", 0),
+                lint("rust", "unused", "deny", -1),
+                lint("rustdoc", "broken_intra_doc_links", "deny", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_lints_toml_workspace_inherited() {
+        let member: toml::Table = r#"
+[package]
+name = "member"
+
+[lints]
+workspace = true
+"#
+        .parse()
+        .unwrap();
+        assert!(lints_inherit_workspace(&member));
+        assert!(parse_lints_toml(&member).is_empty());
+
+        let root: toml::Table = r#"
+[workspace]
+members = ["member"]
+
+[workspace.lints.rust]
+missing_docs = "warn"
+
+[workspace.lints.clippy]
+all = { level = "deny", priority = -1 }
+"#
+        .parse()
+        .unwrap();
+        assert_eq!(
+            parse_workspace_lints_toml(&root),
+            vec![
+                lint("clippy", "all", "deny", -1),
+                lint("rust", "missing_docs", "warn", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_lints_toml_no_lints() {
+        let table: toml::Table = "[package]\nname = \"test-crate\"\n".parse().unwrap();
+        assert!(!lints_inherit_workspace(&table));
+        assert!(parse_lints_toml(&table).is_empty());
+        assert!(parse_workspace_lints_toml(&table).is_empty());
+    }
+
+    // ============ Manifest Metadata Tests ============
+
+    #[test]
+    fn test_lint_cargo_toml_complete() {
+        let toml_str = r#"
+[package]
+name = "complete"
+description = "Does things"
+license-file = "LICENSE"
+repository.workspace = true
+readme = "README.md"
+keywords = ["cli"]
+categories = ["command-line-utilities"]
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert_eq!(lint_cargo_toml(&table), Some(vec![]));
+    }
+
+    #[test]
+    fn test_lint_cargo_toml_incomplete() {
+        let toml_str = r#"
+[package]
+name = "sparse"
+description = "  "
+license = "MIT"
+keywords = []
+"#;
+        let table: toml::Table = toml_str.parse().unwrap();
+        assert_eq!(
+            lint_cargo_toml(&table).unwrap(),
+            vec![
+                "description",
+                "repository",
+                "readme",
+                "keywords",
+                "categories"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_cargo_toml_skips_unpublished() {
+        let private: toml::Table = "[package]\nname = \"app\"\npublish = false\n"
+            .parse()
+            .unwrap();
+        assert_eq!(lint_cargo_toml(&private), None);
+        let virtual_ws: toml::Table = "[workspace]\nmembers = []\n".parse().unwrap();
+        assert_eq!(lint_cargo_toml(&virtual_ws), None);
+    }
+
+    // ============ Build Profile Parser Tests ============
+
+    #[test]
+    fn test_resolve_panic_strategy() {
+        let table: toml::Table = r#"
+[profile.release]
+panic = "abort"
+lto = true
+
+[profile.dist]
+inherits = "release"
+"#
+        .parse()
+        .unwrap();
+        let strategy = |profile: &str| {
+            let panic = resolve_panic_strategy(&table, profile);
+            (panic.strategy, panic.source)
+        };
+
+        assert_eq!(strategy("release"), ("abort".into(), "explicit".into()));
+        assert_eq!(strategy("dist"), ("abort".into(), "inherited".into()));
+        assert_eq!(strategy("dev"), ("unwind".into(), "default".into()));
+        // Cargo ignores `panic` for benches even when release aborts
+        assert_eq!(strategy("bench"), ("unwind".into(), "default".into()));
+
+        let empty = toml::Table::new();
+        assert_eq!(
+            resolve_panic_strategy(&empty, "release").source,
+            "default".to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_build_profiles() {
+        let table: toml::Table = r#"
+[profile.release]
+opt-level = "z"
+lto = "fat"
+codegen-units = 1
+strip = true
+
+[profile.profiling]
+inherits = "release"
+debug = true
+"#
+        .parse()
+        .unwrap();
+        let profiles = parse_build_profiles(&table);
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["dev", "release", "test", "bench", "profiling"]);
+
+        let release = &profiles[1];
+        assert_eq!(release.opt_level.as_deref(), Some("z"));
+        assert_eq!(release.lto.as_deref(), Some("fat"));
+        assert_eq!(release.codegen_units, Some(1));
+        assert_eq!(release.strip.as_deref(), Some("true"));
+        assert_eq!(release.panic.strategy, "unwind");
+
+        assert_eq!(profiles[2].inherits.as_deref(), Some("dev"));
+        assert_eq!(profiles[4].inherits.as_deref(), Some("release"));
+        assert_eq!(profiles[0].opt_level, None);
+    }
+
+    // ============ Cargo.lock Parser Tests ============
+
+    #[test]
+    fn test_parse_cargo_lock() {
+        let content = r#"
+# This file is automatically @generated by Cargo.
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = ["serde"]
+
+[[package]]
+name = "serde"
+version = "1.0.205"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "abc"
+"#;
+        let packages = parse_cargo_lock(content).unwrap();
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].source, None);
+        assert_eq!(
+            packages[1],
+            LockedPackage {
+                name: "serde".to_string(),
+                version: "1.0.205".to_string(),
+                source: Some("registry+https://github.com/rust-lang/crates.io-index".to_string()),
+            }
+        );
+
+        assert!(parse_cargo_lock("version = 3\n").unwrap().is_empty());
+        assert!(parse_cargo_lock("[[package]]\nname = 1\n").is_err());
+    }
 }
@@ -49,7 +49,7 @@ import type {
   CleanEstimates,
   DiskSpaceInfo,
 } from "./types";
-import { formatBytes, formatTimeAgo, formatDuration } from "./utils/formatting";
+import { formatBytes, formatTimeAgo, formatDuration, errorMessage } from "./utils/formatting";
 import { Sidebar, ProjectCard, GearSpinner } from "./components";
 
 hljs.registerLanguage("toml", toml);
@@ -314,7 +314,7 @@ function App() {
     addJob("scan", "Scanning projects...");
     try {
       const found = await invoke<Project[]>("scan_projects", {
-        rootPath: pathToScan,
+        rootPaths: [pathToScan],
       });
       setProjects(found);
     } catch (e) {
@@ -595,6 +595,8 @@ function App() {
         exit_code: number | null;
         output: string[];
         duration_ms: number;
+        error_count: number;
+        warning_count: number;
       }>("cargo-complete", async (event) => {
         setIsStreaming(false);
         setStreamingStartTime(null);
@@ -688,7 +690,7 @@ function App() {
             });
           } catch (e) {
             console.error("Failed to parse coverage results:", e);
-            setCoverageError(String(e));
+            setCoverageError(errorMessage(e));
           }
         } else if (event.payload.command === "tarpaulin" && !event.payload.success) {
           setCoverageError("Coverage analysis failed - check output for details");
@@ -1048,7 +1050,7 @@ function App() {
       setBloatAnalysis(result);
     } catch (e) {
       console.error("Failed to analyze bloat:", e);
-      alert(`Bloat analysis failed: ${e}`);
+      alert(`Bloat analysis failed: ${errorMessage(e)}`);
     }
     removeJob(jobId);
     setAnalyzingBloat(false);
@@ -2091,7 +2093,7 @@ function App() {
                           className="deps-error-row"
                         >
                           <span>{result.project_name}</span>
-                          <span className="error-text">{result.error}</span>
+                          <span className="error-text">{result.error?.message}</span>
                         </div>
                       ))}
                   </div>
@@ -2271,7 +2273,7 @@ function App() {
                           className="deps-error-row"
                         >
                           <span>{result.project_name}</span>
-                          <span className="error-text">{result.error}</span>
+                          <span className="error-text">{result.error?.message}</span>
                         </div>
                       ))}
                   </div>
@@ -2753,7 +2755,7 @@ function App() {
                               >
                                 <span>{result.project_name}</span>
                                 <span className="error-text">
-                                  {result.error}
+                                  {result.error?.message}
                                 </span>
                               </div>
                             ))}
@@ -4176,7 +4178,7 @@ function App() {
                   ) : (
                     <div className="deps-error-message">
                       <XCircle size={16} weight="fill" />
-                      {projectOutdated.error}
+                      {projectOutdated.error?.message}
                     </div>
                   )
                 ) : checkingProjectOutdated ? (
@@ -4299,7 +4301,7 @@ function App() {
                   ) : (
                     <div className="deps-error-message">
                       <XCircle size={16} weight="fill" />
-                      {projectAudit.error}
+                      {projectAudit.error?.message}
                     </div>
                   )
                 ) : (
@@ -4386,7 +4388,7 @@ function App() {
                   ) : (
                     <div className="deps-error-message">
                       <XCircle size={16} weight="fill" />
-                      {projectLicenses.error}
+                      {projectLicenses.error?.message}
                     </div>
                   )
                 ) : (
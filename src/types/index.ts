@@ -38,8 +38,51 @@ export interface Project {
   version: string | null;
   rust_version: string | null;
   homepage: string | null;
+  dep_counts: DepCounts;
 }
 
+export interface DepCounts {
+  normal: number;
+  dev: number;
+  build: number;
+}
+
+export interface DuplicateProjectGroup {
+  name: string;
+  projects: Project[];
+}
+
+export interface ScanDiff {
+  added: Project[];
+  removed: Project[];
+  modified: Project[];
+}
+
+export interface ScanQuery {
+  sort_by?: "name" | "size" | "modified" | "deps";
+  descending?: boolean;
+  only_favorites?: boolean;
+  exclude_hidden?: boolean;
+}
+
+// Structured command errors, tagged by kind
+export type AppError =
+  | {
+      kind: "tool_not_installed";
+      tool: string;
+      install_cmd: string;
+      message: string;
+    }
+  | {
+      kind: "registry_auth";
+      registry: string;
+      message: string;
+    }
+  | {
+      kind: "command_failed" | "parse" | "io" | "not_found" | "internal";
+      message: string;
+    };
+
 export interface CleanResult {
   path: string;
   name: string;
@@ -60,6 +103,23 @@ export interface CleanEstimates {
   projects: ProjectCleanEstimate[];
 }
 
+export interface TargetSubdirSize {
+  name: string;
+  size: number;
+  children: TargetSubdirSize[];
+}
+
+export interface TargetBreakdown {
+  target_path: string;
+  total: number;
+  subdirs: TargetSubdirSize[];
+}
+
+export interface OrphanedTarget {
+  path: string;
+  size: number;
+}
+
 export interface DiskSpaceInfo {
   total_bytes: number;
   free_bytes: number;
@@ -78,6 +138,19 @@ export interface Vulnerability {
   patched_versions: string[];
 }
 
+export interface UpdateRecommendation extends OutdatedDep {
+  fixes: string[];
+}
+
+export interface ProjectUpdateRecommendations {
+  project_path: string;
+  project_name: string;
+  recommendations: UpdateRecommendation[];
+  success: boolean;
+  error: AppError | null;
+  audit_error: AppError | null;
+}
+
 export interface AuditWarning {
   kind: string;
   package: string;
@@ -87,16 +160,78 @@ export interface AuditWarning {
   url: string | null;
 }
 
+export interface AdvisoryOccurrence {
+  id: string;
+  package: string;
+  severity: string;
+  affected_projects: string[];
+}
+
+export interface AuditSummary {
+  total_vulns: number;
+  by_severity: Record<string, number>;
+  advisories: AdvisoryOccurrence[];
+}
+
+export interface CommonVulnerableDep {
+  package: string;
+  version: string;
+  advisory_id: string;
+  projects: string[];
+}
+
 export interface AuditResult {
   project_path: string;
   project_name: string;
   vulnerabilities: Vulnerability[];
   warnings: AuditWarning[];
   success: boolean;
-  error: string | null;
+  error: AppError | null;
 }
 
 // Cargo command types
+export interface CompilerDiagnostic {
+  level: string;
+  message: string;
+  code: string | null;
+  file: string | null;
+  line: number | null;
+  column: number | null;
+  rendered: string | null;
+  target_name: string | null;
+  target_kind: string[];
+}
+
+export interface ExampleCheck {
+  example: string;
+  success: boolean;
+  error: string | null;
+}
+
+export interface CompilerArtifact {
+  package_id: string;
+  target_name: string;
+  target_kind: string[];
+  filenames: string[];
+  fresh: boolean;
+}
+
+export interface CargoMessages {
+  diagnostics: CompilerDiagnostic[];
+  artifacts: CompilerArtifact[];
+  build_success: boolean | null;
+}
+
+export interface CargoMessagesResult extends CargoCommandResult {
+  messages: CargoMessages | null;
+}
+
+export interface MissingDoc {
+  file: string;
+  line: number;
+  item: string;
+}
+
 export interface CargoCommandResult {
   project_path: string;
   command: string;
@@ -131,12 +266,21 @@ export interface OutdatedResult {
   project_name: string;
   dependencies: OutdatedDep[];
   success: boolean;
-  error: string | null;
+  error: AppError | null;
+}
+
+export interface DepContribution {
+  project: string;
+  kind: string;
+  target_cfg: string | null;
+  artifact: string | null;
 }
 
 export interface VersionUsage {
   version: string;
   projects: string[];
+  kinds: string[];
+  contributions: DepContribution[];
 }
 
 export interface DepUsage {
@@ -145,6 +289,140 @@ export interface DepUsage {
   project_count: number;
 }
 
+export interface TransitiveDepCount {
+  direct: number;
+  transitive_unique: number;
+}
+
+export interface DepWeight {
+  name: string;
+  transitive_count: number;
+  exclusive_count: number;
+}
+
+export interface DuplicatedDep {
+  name: string;
+  version_count: number;
+}
+
+export interface DuplicationSummary {
+  total_unique: number;
+  duplicated: number;
+  worst_offenders: DuplicatedDep[];
+}
+
+export interface BuildScriptInfo {
+  has_build_rs: boolean;
+  build_rs_lines: number;
+  emits_rerun_if: string[];
+  links_native: boolean;
+}
+
+export type WorkspaceProblem = "missing" | "no_manifest" | "no_matches";
+
+export interface WorkspaceIssue {
+  pattern: string;
+  resolved_path: string;
+  problem: WorkspaceProblem;
+}
+
+export interface ManifestMetadataReport {
+  project_path: string;
+  project_name: string;
+  missing: string[];
+}
+
+export interface MissingFieldCount {
+  field: string;
+  projects: number;
+}
+
+export interface ManifestMetadataAudit {
+  projects: ManifestMetadataReport[];
+  missing_counts: MissingFieldCount[];
+}
+
+export interface ProjectFeatureCount {
+  project: string;
+  feature_count: number;
+}
+
+export interface FeatureSummary {
+  total_distinct: number;
+  projects_with_default: number;
+  most_featured: ProjectFeatureCount[];
+}
+
+export interface MsrvMismatch {
+  member: string;
+  member_msrv: string;
+  root_msrv: string;
+}
+
+export interface Buildability {
+  project: string;
+  msrv: string | null;
+  buildable_with_default: boolean;
+  needs_toolchain: string | null;
+  needs_install: boolean;
+}
+
+export interface LockfileStatus {
+  has_lockfile: boolean;
+  is_gitignored: boolean;
+  is_binary_crate: boolean;
+  recommendation: string | null;
+}
+
+export interface UnsafeUsage {
+  unsafe_blocks: number;
+  unsafe_fns: number;
+  unsafe_impls: number;
+  unsafe_extern_blocks: number;
+  files_with_unsafe: number;
+}
+
+export interface CrateAttribute {
+  name: string;
+  args: string[];
+}
+
+export interface CrateRootAttributes {
+  file: string;
+  attributes: CrateAttribute[];
+  bans_unsafe: boolean;
+  no_std: boolean;
+}
+
+export interface EditionGroup {
+  edition: string;
+  outdated: boolean;
+  projects: string[];
+}
+
+export interface GitDep {
+  name: string;
+  url: string;
+  rev: string | null;
+  branch: string | null;
+  tag: string | null;
+  locked_rev: string | null;
+}
+
+export interface PathDep {
+  name: string;
+  path: string;
+  resolved: string;
+  exists: boolean;
+}
+
+export interface ResolvedDependency {
+  name: string;
+  kind: string;
+  declared: string | null;
+  resolved: string | null;
+}
+
 export interface DepAnalysis {
   dependencies: DepUsage[];
   total_unique_deps: number;
@@ -192,7 +470,7 @@ export interface LicenseResult {
   project_name: string;
   licenses: LicenseInfo[];
   success: boolean;
-  error: string | null;
+  error: AppError | null;
 }
 
 export interface LicenseAnalysis {
@@ -216,12 +494,55 @@ export interface GitTag {
   commit_hash: string;
 }
 
+export interface TestRunSummary {
+  project: string;
+  passed: number;
+  failed: number;
+  success: boolean;
+  error: string | null;
+}
+
+export interface CommitInfo {
+  hash: string;
+  subject: string;
+}
+
+export interface UnreleasedChanges {
+  last_tag: string | null;
+  commit_count: number;
+  commits: CommitInfo[];
+}
+
+export interface ChangelogEntry {
+  version: string;
+  date: string | null;
+  sections: Record<string, string[]>;
+}
+
+export type ReleaseIssue = "no_changelog_entry" | "untagged" | "manifest_ahead_of_tag";
+
+export interface Release {
+  tag: string | null;
+  version: string;
+  date: string | null;
+  notes: Record<string, string[]>;
+  commit_hash: string | null;
+  issues: ReleaseIssue[];
+}
+
 export interface GitStats {
   contributors: number;
   commits: number;
   branches: number;
   tags: number;
   first_commit_date: string | null;
+  last_commit_timestamp: number | null;
+}
+
+export interface ArchivalCandidate {
+  project: Project;
+  days_since_modified: number;
+  days_since_commit: number | null;
 }
 
 // IDE types
@@ -231,6 +552,12 @@ export interface InstalledIde {
   command: string;
 }
 
+export interface IdeOpenResult {
+  path: string;
+  success: boolean;
+  error: string | null;
+}
+
 // Documentation types
 export interface DocResult {
   success: boolean;
@@ -248,6 +575,7 @@ export interface CargoFeature {
 export interface CargoFeatures {
   features: CargoFeature[];
   default_features: string[];
+  has_default: boolean;
 }
 
 // Binary size types
@@ -275,6 +603,7 @@ export interface BloatFunction {
   size: number;
   size_percent: number;
   crate_name: string | null;
+  module: string | null;
 }
 
 export interface BloatAnalysis {
@@ -306,6 +635,50 @@ export interface MsrvInfo {
   edition: string | null;
 }
 
+export interface PanicStrategy {
+  profile: string;
+  strategy: string;
+  source: string;
+}
+
+export interface BuildProfile {
+  name: string;
+  inherits: string | null;
+  opt_level: string | null;
+  lto: string | null;
+  codegen_units: number | null;
+  strip: string | null;
+  panic: PanicStrategy;
+}
+
+export interface MsrvDetection {
+  detected_msrv: string | null;
+  declared_msrv: string | null;
+  matches: boolean;
+}
+
+export interface XtaskInfo {
+  has_xtask: boolean;
+  via_alias: boolean;
+  member_path: string | null;
+}
+
+export interface VendoredCrates {
+  is_vendored: boolean;
+  vendor_dir: string | null;
+  vendored_crate_count: number;
+}
+
+export interface CargoAlias {
+  alias: string;
+  expansion: string;
+}
+
+export interface ResolverInfo {
+  resolver: string;
+  inferred: boolean;
+}
+
 // Workspace types
 export interface WorkspaceMember {
   name: string;
@@ -345,6 +718,17 @@ export interface RustVersionInfo {
   active_toolchain: string | null;
 }
 
+export interface InstalledTargets {
+  host_triple: string | null;
+  targets: string[];
+}
+
+export interface RequiredTarget {
+  target: string;
+  installed: boolean;
+  source: string;
+}
+
 // Homebrew types
 export interface HomebrewStatus {
   installed_via_homebrew: boolean;
@@ -383,7 +767,23 @@ export interface SearchResult {
   context_after: ContextLine[];
 }
 
+export interface SearchResultEvent extends SearchResult {
+  search_id: string;
+}
+
+export interface SearchCompleteEvent {
+  search_id: string;
+  query: string;
+  total: number;
+  truncated: boolean;
+}
+
 // Cache types
+export interface CachedDepCount {
+  lock_mtime: number;
+  counts: TransitiveDepCount;
+}
+
 export interface ScanCache {
   outdated_results: OutdatedResult[] | null;
   outdated_timestamp: number | null;
@@ -395,6 +795,144 @@ export interface ScanCache {
   toolchain_timestamp: number | null;
   license_analysis: LicenseAnalysis | null;
   license_timestamp: number | null;
+  build_times: Record<string, BuildTimeSample[]>;
+  transitive_dep_counts: Record<string, CachedDepCount>;
+  disk_usage_history: DiskUsageSample[];
+  analysis_times: Record<string, ProjectAnalysisTimes>;
+}
+
+export interface ProjectAnalysisTimes {
+  outdated_at: number | null;
+  audit_at: number | null;
+}
+
+export interface DiskUsageSample {
+  timestamp: number;
+  root: string;
+  total_target_size: number;
+  project_count: number;
+}
+
+export interface BuildTimeSample {
+  timestamp: number;
+  duration_ms: number;
+  incremental: boolean;
+  release: boolean;
+}
+
+// GitHub Actions types
+export interface WorkflowRun {
+  status: string;
+  conclusion: string | null;
+  html_url: string;
+  created_at: string;
+}
+
+// Repo hygiene types
+export interface RepoHygiene {
+  git_hooks: string[];
+  pre_commit_config: string | null;
+  husky_dir: string | null;
+  rustfmt_config: string | null;
+  clippy_config: string | null;
+  deny_config: string | null;
+}
+
+export interface DiffHunk {
+  start_line: number;
+  lines: string[];
+}
+
+export interface SemverIssue {
+  kind: string;
+  message: string;
+  location: string;
+}
+
+export interface FileDiff {
+  path: string;
+  hunks: DiffHunk[];
+}
+
+export interface LintSetting {
+  group: string;
+  name: string;
+  level: string;
+  priority: number;
+}
+
+export interface DenyConfig {
+  licenses: { allow: string[]; deny: string[] };
+  bans: { deny: string[] };
+  advisories: { ignore: string[] };
+}
+
+export interface LintConfigs {
+  rustfmt: Record<string, unknown> | null;
+  clippy: Record<string, unknown> | null;
+}
+
+// Crates.io types
+export interface CrateMeta {
+  name: string;
+  downloads: number;
+  recent_downloads: number | null;
+  latest_version: string;
+  max_stable: string | null;
+  description: string | null;
+  homepage: string | null;
+  documentation: string | null;
+  repository: string | null;
+}
+
+export interface FeatureCheckResult {
+  feature: string;
+  success: boolean;
+  error: string | null;
+}
+
+export interface BenchResult {
+  name: string;
+  mean_ns: number;
+  median_ns: number;
+  std_dev_ns: number;
+}
+
+export interface FormatFileResult {
+  success: boolean;
+  content: string;
+  stderr: string;
+}
+
+export interface ProjectLicense {
+  spdx: string | null;
+  license_file: string | null;
+  detected_from_file: string | null;
+}
+
+export interface VersionPin {
+  name: string;
+  current_req: string;
+  suggested_req: string;
+}
+
+export interface DocsRsStatus {
+  build_status: string;
+  version: string;
+  doc_url: string;
+}
+
+// Cargo target types
+export interface CargoTargets {
+  bins: string[];
+  examples: string[];
+  benches: string[];
+  integration_tests: string[];
+}
+
+export interface RunTarget {
+  kind: string;
+  name: string;
 }
 
 // Tool status types
@@ -402,10 +940,17 @@ export interface ToolStatus {
   name: string;
   command: string;
   installed: boolean;
+  version: string | null;
   install_cmd: string;
   description: string;
 }
 
+export interface ToolInstallSummary {
+  succeeded: string[];
+  failed: string[];
+  duration_ms: number;
+}
+
 // Test result types
 export interface TestResult {
   name: string;
@@ -36,3 +36,13 @@ export function formatDuration(ms: number | undefined): string {
   const seconds = ((ms % 60000) / 1000).toFixed(0);
   return `${minutes}m ${seconds}s`;
 }
+
+/**
+ * Get a displayable message from a thrown invoke error (AppError object or plain string)
+ */
+export function errorMessage(error: unknown): string {
+  if (error && typeof error === "object" && "message" in error) {
+    return String((error as { message: unknown }).message);
+  }
+  return String(error);
+}